@@ -1,7 +1,10 @@
 use crate::Axis;
 
-use super::CrossSection;
+use serde::{Deserialize, Serialize};
 
+use super::{CrossSection, CrsDef, Shape};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Box {
     y: f64,
     z: f64,
@@ -33,6 +36,12 @@ impl Default for Box {
 }
 
 impl CrossSection for Box {
+    fn definition(&self) -> CrsDef {
+        CrsDef::Box(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        Shape::Box
+    }
     fn width(&self) -> f64 {
         self.y
     }
@@ -42,12 +51,30 @@ impl CrossSection for Box {
     fn area(&self) -> f64 {
         self.y * self.z - self.y_inner() * self.z_inner()
     }
+    fn t_max(&self) -> f64 {
+        self.t
+    }
+    fn width_at(&self, z: f64, axis: Axis) -> Option<f64> {
+        let (width, depth) = match axis {
+            Axis::X | Axis::Y => (self.y, self.z),
+            Axis::Z => (self.z, self.y),
+        };
+        Some(if z.abs() > depth / 2.0 {
+            0.0
+        } else if z.abs() > depth / 2.0 - self.t {
+            width
+        } else {
+            2.0 * self.t
+        })
+    }
 
     #[allow(non_snake_case)]
     fn I(&self, axis: Axis) -> f64 {
         match axis {
             Axis::X => {
-                todo!()
+                // Bredt's formula for the thin-walled closed section along the midline
+                let (y, z) = (self.y - self.t, self.z - self.t);
+                4.0 * (y * z).powi(2) * self.t / (2.0 * (y + z))
             }
             Axis::Y => (self.y * self.z.powi(3) - self.y_inner() * self.z_inner().powi(3)) / 12.0,
             Axis::Z => (self.z * self.y.powi(3) - self.z_inner() * self.y_inner().powi(3)) / 12.0,
@@ -55,20 +82,44 @@ impl CrossSection for Box {
     }
     fn w_el(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            // Bredt's formula with the area enclosed by the midline
+            Axis::X => 2.0 * (self.y - self.t) * (self.z - self.t) * self.t,
             Axis::Y => self.I(axis) / (self.z / 2.0),
             Axis::Z => self.I(axis) / (self.y / 2.0),
         }
     }
 
-    fn w_pl(&self, _axis: Axis) -> f64 {
-        todo!()
+    fn w_pl(&self, axis: Axis) -> f64 {
+        match axis {
+            // The shear flow of thin closed sections is uniform already at first yield
+            Axis::X => self.w_el(axis),
+            Axis::Y => {
+                self.y
+                    .mul_add(self.z.powi(2), -self.y_inner() * self.z_inner().powi(2))
+                    / 4.0
+            }
+            Axis::Z => {
+                self.z
+                    .mul_add(self.y.powi(2), -self.z_inner() * self.y_inner().powi(2))
+                    / 4.0
+            }
+        }
     }
 
-    fn area_shear(&self, _axis: Axis) -> f64 {
-        todo!()
+    /// Shear areas of rectangular hollow sections of uniform thickness according to
+    /// NS-EN 1993-1-1 6.2.6(3)
+    fn area_shear(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.area(),
+            Axis::Y => self.area() * self.y / (self.y + self.z),
+            Axis::Z => self.area() * self.z / (self.y + self.z),
+        }
+    }
+    fn web(&self) -> Option<(f64, f64)> {
+        Some((self.z_inner(), self.t))
+    }
+    fn flange(&self) -> Option<(f64, f64)> {
+        Some((self.y, self.t))
     }
 }
 
@@ -113,6 +164,15 @@ mod tests {
         assert_zeq!(centroid.1, 50.0);
     }
 
+    #[test]
+    fn torsion_constant() {
+        let crs = Box::new(50.0, 100.0, 10.0);
+        assert_zeq!(crs.I(Axis::X), 1_993_846.153_846);
+        assert_zeq!(crs.w_el(Axis::X), 72_000.0);
+        assert_zeq!(crs.w_pl(Axis::X), crs.w_el(Axis::X));
+        assert_zeq!(crs.area_shear(Axis::X), crs.area());
+    }
+
     #[test]
     fn second_moment_of_area() {
         let width = 50.0;
@@ -2,8 +2,11 @@ use std::f64::consts::PI;
 
 use crate::Axis;
 
-use super::CrossSection;
+use serde::{Deserialize, Serialize};
 
+use super::{CrossSection, CrsDef, Shape};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Circle {
     d: f64,
 }
@@ -26,6 +29,12 @@ impl Default for Circle {
 }
 
 impl CrossSection for Circle {
+    fn definition(&self) -> CrsDef {
+        CrsDef::Circle(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        Shape::Circle
+    }
     fn width(&self) -> f64 {
         self.d
     }
@@ -35,36 +44,40 @@ impl CrossSection for Circle {
     fn area(&self) -> f64 {
         PI * self.r().powi(2)
     }
+    fn section_factor(&self) -> f64 {
+        1000.0 * PI * self.d / self.area()
+    }
+    fn t_max(&self) -> f64 {
+        self.d
+    }
+    fn width_at(&self, z: f64, _axis: Axis) -> Option<f64> {
+        Some(2.0 * ((self.r() - z) * (self.r() + z)).max(0.0).sqrt())
+    }
 
     fn I(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => PI / 2.0 * self.r().powi(4),
             Axis::Y | Axis::Z => PI / 4.0 * self.r().powi(4),
         }
     }
 
     fn w_el(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => PI / 2.0 * self.r().powi(3),
             Axis::Y | Axis::Z => PI / 4.0 * self.r().powi(3),
         }
     }
 
     fn w_pl(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => 2.0 * PI / 3.0 * self.r().powi(3),
             Axis::Y | Axis::Z => (4.0 / 3.0) * self.r().powi(3),
         }
     }
 
+    /// Shear area of solid bars according to NS-EN 1993-1-1 6.2.6(3)
     fn area_shear(&self, _axis: Axis) -> f64 {
-        todo!()
+        self.area()
     }
 }
 
@@ -0,0 +1,233 @@
+use std::f64::consts::PI;
+
+use crate::Axis;
+
+use serde::{Deserialize, Serialize};
+
+use super::{CrossSection, CrsDef, Shape};
+
+/// Doubly symmetric rolled I-section with root radius between web and flanges
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IBeam {
+    h: f64,
+    b: f64,
+    tw: f64,
+    tf: f64,
+    r: f64,
+}
+
+impl IBeam {
+    #[must_use]
+    pub const fn new(h: f64, b: f64, tw: f64, tf: f64, r: f64) -> Self {
+        Self { h, b, tw, tf, r }
+    }
+
+    /// Clear height between the flanges
+    fn hi(&self) -> f64 {
+        2.0f64.mul_add(-self.tf, self.h)
+    }
+}
+
+impl Default for IBeam {
+    /// HEB 300
+    fn default() -> Self {
+        Self::new(300.0, 300.0, 11.0, 19.0, 27.0)
+    }
+}
+
+impl CrossSection for IBeam {
+    fn definition(&self) -> CrsDef {
+        CrsDef::IBeam(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        Shape::IBeam
+    }
+    fn width(&self) -> f64 {
+        self.b
+    }
+    fn height(&self) -> f64 {
+        self.h
+    }
+    fn area(&self) -> f64 {
+        (4.0 - PI).mul_add(
+            self.r.powi(2),
+            (2.0 * self.b).mul_add(self.tf, self.hi() * self.tw),
+        )
+    }
+    fn t_max(&self) -> f64 {
+        self.tf.max(self.tw)
+    }
+    /// Width of the plates, neglecting the root fillets
+    fn width_at(&self, z: f64, axis: Axis) -> Option<f64> {
+        let z = z.abs();
+        Some(match axis {
+            Axis::X | Axis::Y if z > self.h / 2.0 => 0.0,
+            Axis::X | Axis::Y if z > self.hi() / 2.0 => self.b,
+            Axis::X | Axis::Y => self.tw,
+            Axis::Z if z > self.b / 2.0 => 0.0,
+            Axis::Z if z > self.tw / 2.0 => 2.0 * self.tf,
+            Axis::Z => self.h,
+        })
+    }
+    /// Clear depth between the flanges, including the root fillets
+    fn web(&self) -> Option<(f64, f64)> {
+        Some((self.hi(), self.tw))
+    }
+    /// Full width of each flange
+    fn flange(&self) -> Option<(f64, f64)> {
+        Some((self.b, self.tf))
+    }
+    /// Perimeter including the root fillets
+    fn section_factor(&self) -> f64 {
+        let perimeter = 2.0f64.mul_add(PI, -8.0).mul_add(
+            self.r,
+            2.0f64.mul_add(self.h, 4.0f64.mul_add(self.b, -2.0 * self.tw)),
+        );
+        1000.0 * perimeter / self.area()
+    }
+    /// 0.9 of the box value over the section factor, for nominal fire exposure
+    fn shadow_factor(&self) -> f64 {
+        0.9 * 2000.0 * (self.b + self.h) / self.area() / self.section_factor()
+    }
+
+    #[allow(non_snake_case)]
+    fn I(&self, axis: Axis) -> f64 {
+        // The four root fillets together contribute 0.03 r^4 about their own centroids, and
+        // each 0.2146 r^2 in area with the centroid 0.2234 r from the web and flange
+        let fillets = 0.03 * self.r.powi(4);
+        match axis {
+            Axis::X => {
+                // Plates plus the web-flange junctions with their root fillets
+                let (tw, tf, r) = (self.tw, self.tf, self.r);
+                let alpha = 0.0725f64.mul_add(
+                    -(tw / tf).powi(2),
+                    0.0865f64.mul_add(
+                        -r * tw / tf.powi(2),
+                        0.1355f64.mul_add(r / tf, 0.2204f64.mul_add(tw / tf, -0.042)),
+                    ),
+                );
+                let d = tw.mul_add(r + tw / 4.0, (tf + r).powi(2)) / 2.0f64.mul_add(r, tf);
+                let plates = (2.0 * self.b).mul_add(tf.powi(3), self.hi() * tw.powi(3)) / 3.0;
+                (2.0 * alpha).mul_add(d.powi(4), 0.42f64.mul_add(-tf.powi(4), plates))
+            }
+            Axis::Y => {
+                let plates = self
+                    .b
+                    .mul_add(self.h.powi(3), -(self.b - self.tw) * self.hi().powi(3))
+                    / 12.0;
+                let steiner =
+                    0.2146 * self.r.powi(2) * 0.4468f64.mul_add(-self.r, self.hi()).powi(2);
+                plates + fillets + steiner
+            }
+            Axis::Z => {
+                let plates =
+                    (2.0 * self.tf).mul_add(self.b.powi(3), self.hi() * self.tw.powi(3)) / 12.0;
+                let steiner = 0.2146 * self.r.powi(2) * 0.4468f64.mul_add(self.r, self.tw).powi(2);
+                plates + fillets + steiner
+            }
+        }
+    }
+
+    /// Flanges only, about the shear centre
+    #[allow(non_snake_case)]
+    fn I_w(&self) -> f64 {
+        self.I(Axis::Z) * (self.h - self.tf).powi(2) / 4.0
+    }
+
+    fn w_el(&self, axis: Axis) -> f64 {
+        match axis {
+            // Open thin-walled section, with the largest shear stress in the thickest plate
+            Axis::X => self.I(axis) / self.t_max(),
+            Axis::Y => self.I(axis) / (self.h / 2.0),
+            Axis::Z => self.I(axis) / (self.b / 2.0),
+        }
+    }
+
+    fn w_pl(&self, axis: Axis) -> f64 {
+        match axis {
+            // Thin-walled plates, neglecting the root fillets
+            Axis::X => (2.0 * self.b).mul_add(self.tf.powi(2), self.hi() * self.tw.powi(2)) / 2.0,
+            Axis::Y => {
+                let web = self.tw * self.h.powi(2) / 4.0;
+                let flanges = (self.b - self.tw) * (self.h - self.tf) * self.tf;
+                let fillets = ((4.0 - PI) / 2.0 * self.r.powi(2)).mul_add(
+                    self.hi(),
+                    (3.0f64.mul_add(PI, -10.0) / 3.0) * self.r.powi(3),
+                );
+                web + flanges + fillets
+            }
+            Axis::Z => {
+                let flanges = self.b.powi(2) * self.tf / 2.0;
+                let web = self.hi() * self.tw.powi(2) / 4.0;
+                let fillets = (10.0 / 3.0 - PI)
+                    .mul_add(self.r.powi(3), (2.0 - PI / 2.0) * self.tw * self.r.powi(2));
+                flanges + web + fillets
+            }
+        }
+    }
+
+    /// Shear areas of rolled sections according to NS-EN 1993-1-1 6.2.6(3), where the lower
+    /// bound `eta h_w t_w` along z is left to the member as `eta` depends on the National Annex
+    fn area_shear(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.area(),
+            Axis::Y => 2.0 * self.b * self.tf,
+            Axis::Z => (2.0 * self.b).mul_add(
+                -self.tf,
+                2.0f64
+                    .mul_add(self.r, self.tw)
+                    .mul_add(self.tf, self.area()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    // Reference values for HEB 300 from the embeded catalogue, rounded as in the source
+    #[test]
+    fn area_cross_section() {
+        let crs = IBeam::default();
+        assert_zeq!((crs.area() / 1e2).round(), 149.0);
+    }
+
+    #[test]
+    fn second_moment_of_area() {
+        let crs = IBeam::default();
+        assert_zeq!((crs.I(Axis::Y) / 1e4).round(), 25_166.0);
+        assert_zeq!((crs.I(Axis::Z) / 1e4).round(), 8_563.0);
+    }
+
+    #[test]
+    fn torsion_constant() {
+        // The catalogue gives 186 cm^4 for HEB 300
+        let crs = IBeam::default();
+        assert_zeq!((crs.I(Axis::X) / 1e4).round(), 189.0);
+    }
+
+    #[test]
+    fn bending_moment() {
+        let crs = IBeam::default();
+        assert_zeq!((crs.w_el(Axis::Y) / 1e3).round(), 1_678.0);
+        assert_zeq!((crs.w_pl(Axis::Y) / 1e3).round(), 1_869.0);
+        assert_zeq!((crs.w_pl(Axis::Z) / 1e3).round(), 870.0);
+        assert_zeq!(crs.w_pl(Axis::X), 124_151.0);
+    }
+
+    #[test]
+    fn section_factor() {
+        let crs = IBeam::default();
+        assert_zeq!(crs.section_factor().round(), 116.0);
+        assert_zeq!((crs.shadow_factor() * 100.0).round(), 62.0);
+    }
+
+    #[test]
+    fn shear_area() {
+        let crs = IBeam::default();
+        assert_zeq!((crs.area_shear(Axis::Z) / 1e2).round(), 47.0);
+        assert_zeq!(crs.area_shear(Axis::X), crs.area());
+    }
+}
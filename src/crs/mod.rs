@@ -1,14 +1,91 @@
 pub mod r#box;
 pub mod circle;
+pub mod ibeam;
 pub mod rect;
+pub mod reinforced;
 pub mod standard;
+pub mod tee;
 pub mod tube;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{json, Value};
 
 use crate::Axis;
 
+/// Serialisable definition of any cross-section, tagged by its type so a
+/// `Box<dyn CrossSection>` can be written to and read back from JSON or RON
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum CrsDef {
+    Rect(rect::Rect),
+    Circle(circle::Circle),
+    Tube(tube::Tube),
+    Box(r#box::Box),
+    IBeam(ibeam::IBeam),
+    Tee(tee::Tee),
+    Preset(standard::PresetCrs),
+}
+
+impl From<CrsDef> for Box<dyn CrossSection> {
+    fn from(def: CrsDef) -> Self {
+        match def {
+            CrsDef::Rect(crs) => Box::new(crs),
+            CrsDef::Circle(crs) => Box::new(crs),
+            CrsDef::Tube(crs) => Box::new(crs),
+            CrsDef::Box(crs) => Box::new(crs),
+            CrsDef::IBeam(crs) => Box::new(crs),
+            CrsDef::Tee(crs) => Box::new(crs),
+            CrsDef::Preset(crs) => Box::new(crs),
+        }
+    }
+}
+
+impl Serialize for dyn CrossSection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.definition().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<dyn CrossSection> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CrsDef::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Family of the cross-section, choosing between formulations that depend on its shape
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Shape {
+    /// Solid rectangle
+    Rect,
+    /// Solid circle
+    Circle,
+    /// Circular hollow section
+    Tube,
+    /// Rectangular hollow section of uniform thickness
+    Box,
+    /// Doubly symmetric I or H-section
+    IBeam,
+    /// Equal or unequal leg angle
+    Angle,
+    /// T-section, symmetric about the z-axis
+    Tee,
+}
+
+/// Manufacturing process of the section, affecting its residual stresses
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Fabrication {
+    /// Hot rolled, or hot finished for hollow sections
+    #[default]
+    Rolled,
+    /// Welded from plates
+    Welded,
+    /// Cold formed
+    ColdFormed,
+}
+
 pub trait CrossSection {
+    /// Definition of the section that can be serialised and turned back into a section
+    fn definition(&self) -> CrsDef;
+    fn shape(&self) -> Shape;
     /// Width of bounding box (along y-axis) in
     /// [mm]
     fn width(&self) -> f64;
@@ -18,8 +95,11 @@ pub trait CrossSection {
     /// Area in
     /// [mm^2]
     fn area(&self) -> f64;
-    /// Area in
-    /// [mm^2]
+    /// Thickness of the thickest plate element, governing the yield strength, in
+    /// [mm]
+    fn t_max(&self) -> f64;
+    /// Shear area in [mm^2] along a given axis, where `Axis::X`, which has no shear area,
+    /// gives the gross area
     fn area_shear(&self, axis: Axis) -> f64;
     /// Yc, Zc, as measured from bottom left corner in
     /// [mm]
@@ -27,13 +107,52 @@ pub trait CrossSection {
         (self.width() / 2.0, self.height() / 2.0)
     }
     #[allow(non_snake_case)]
-    /// Inertia in [mm^4] about a given axis
+    /// Inertia in [mm^4] about a given axis, where `Axis::X` gives the St. Venant torsion
+    /// constant
     fn I(&self, axis: Axis) -> f64;
     #[allow(non_snake_case)]
-    /// Inertia in [mm^4] about a given axis
+    /// Warping constant in [mm^6], zero for sections where warping is negligible, such as
+    /// hollow and solid sections
+    fn I_w(&self) -> f64 {
+        0.0
+    }
+    /// Offset `(y_0, z_0)` of the shear centre from the centroid in [mm], zero for doubly
+    /// symmetric sections
+    fn shear_centre(&self) -> (f64, f64) {
+        (0.0, 0.0)
+    }
+    #[allow(non_snake_case)]
+    /// Elastic section modulus in [mm^3] about a given axis, where `Axis::X` gives the
+    /// torsional section modulus
     fn w_el(&self, axis: Axis) -> f64;
-    /// Bending moment in [mm^3] about a given axis
+    /// Plastic section modulus in [mm^3] about a given axis, where `Axis::X` gives the plastic
+    /// torsional modulus, the fully plastic St. Venant torque over the shear yield stress
     fn w_pl(&self, axis: Axis) -> f64;
+    /// Section factor Am/V, the surface exposed to fire per unit volume, in
+    /// [1/m], taken from the bounding box unless the section knows its perimeter
+    fn section_factor(&self) -> f64 {
+        2000.0 * (self.width() + self.height()) / self.area()
+    }
+    /// Correction factor for the shadow effect, NS-EN 1993-1-2 4.2.5.1(2), 1.0 for convex
+    /// sections
+    fn shadow_factor(&self) -> f64 {
+        1.0
+    }
+    /// Width in [mm] at the distance `z` from the centroid, measured along the depth resisting
+    /// moments about `axis`, or `None` if the shape of the section is not known
+    fn width_at(&self, _z: f64, _axis: Axis) -> Option<f64> {
+        None
+    }
+    /// Clear depth `h_w` and thickness `t_w` of each web resisting shear along the z-axis in
+    /// [mm], or `None` for sections without webs
+    fn web(&self) -> Option<(f64, f64)> {
+        None
+    }
+    /// Width `b` and thickness `t_f` of each flange in [mm], or `None` for sections without
+    /// flanges
+    fn flange(&self) -> Option<(f64, f64)> {
+        None
+    }
 
     fn json(&self) -> Value {
         let jsonout = json!({
@@ -44,6 +163,9 @@ pub trait CrossSection {
             "A_v_z":  self.area_shear(Axis::Z),
             "I_y": self.I(Axis::Y),
             "I_z": self.I(Axis::Z),
+            "I_t": self.I(Axis::X),
+            "I_w": self.I_w(),
+            "w_t": self.w_el(Axis::X),
             "w_el_y": self.w_el(Axis::Y),
             "w_pl_y": self.w_pl(Axis::Y),
             "w_el_z": self.w_el(Axis::Z),
@@ -52,3 +174,52 @@ pub trait CrossSection {
         jsonout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    fn sections() -> Vec<Box<dyn CrossSection>> {
+        vec![
+            Box::new(rect::Rect::new(100.0, 300.0)),
+            Box::new(circle::Circle::new(100.0)),
+            Box::new(tube::Tube::new(100.0, 10.0)),
+            Box::new(r#box::Box::default()),
+            Box::new(ibeam::IBeam::default()),
+            Box::new(tee::Tee::default()),
+            Box::new(standard::PresetCrs::from_name("HEB 300").expect("Couldnt create crs")),
+        ]
+    }
+
+    #[test]
+    fn sections_round_trip_through_json() {
+        for crs in sections() {
+            let text = serde_json::to_string(&crs).expect("Couldnt serialise");
+            let back: Box<dyn CrossSection> = serde_json::from_str(&text).expect(&text);
+            assert_eq!(serde_json::to_string(&back).expect(&text), text);
+            assert_zeq!(back.area(), crs.area());
+        }
+    }
+
+    #[test]
+    fn sections_round_trip_through_ron() {
+        for crs in sections() {
+            let text = ron::to_string(&crs).expect("Couldnt serialise");
+            let back: Box<dyn CrossSection> = ron::from_str(&text).expect(&text);
+            assert_eq!(ron::to_string(&back).expect(&text), text);
+            assert_zeq!(back.I(Axis::Y), crs.I(Axis::Y));
+        }
+    }
+
+    #[test]
+    fn section_definition_is_tagged_by_type() {
+        let crs: Box<dyn CrossSection> =
+            ron::from_str("Rect((y: 100.0, z: 300.0))").expect("Couldnt deserialise");
+        assert_zeq!(crs.area(), 30_000.0);
+
+        let crs: Box<dyn CrossSection> =
+            serde_json::from_str(r#"{"Tube":{"d":100.0,"t":10.0}}"#).expect("Couldnt deserialise");
+        assert_zeq!(crs.area(), tube::Tube::new(100.0, 10.0).area());
+    }
+}
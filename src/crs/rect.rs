@@ -1,7 +1,10 @@
 use crate::Axis;
 
-use super::CrossSection;
+use serde::{Deserialize, Serialize};
 
+use super::{CrossSection, CrsDef, Shape};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Rect {
     y: f64,
     z: f64,
@@ -19,6 +22,12 @@ impl Rect {
     }
 }
 impl CrossSection for Rect {
+    fn definition(&self) -> CrsDef {
+        CrsDef::Rect(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        Shape::Rect
+    }
     fn width(&self) -> f64 {
         self.y
     }
@@ -28,12 +37,25 @@ impl CrossSection for Rect {
     fn area(&self) -> f64 {
         self.y * self.z
     }
+    fn t_max(&self) -> f64 {
+        self.y.min(self.z)
+    }
+    fn width_at(&self, z: f64, axis: Axis) -> Option<f64> {
+        let (width, depth) = match axis {
+            Axis::X | Axis::Y => (self.y, self.z),
+            Axis::Z => (self.z, self.y),
+        };
+        Some(if z.abs() > depth / 2.0 { 0.0 } else { width })
+    }
 
     #[allow(non_snake_case)]
     fn I(&self, axis: Axis) -> f64 {
         match axis {
             Axis::X => {
-                todo!()
+                let (b, h) = (self.y.min(self.z), self.y.max(self.z));
+                let factor =
+                    (0.21 * b / h).mul_add(-(1.0 - b.powi(4) / (12.0 * h.powi(4))), 1.0 / 3.0);
+                h * b.powi(3) * factor
             }
             Axis::Y => self.y * self.z.powi(3) / 12.0,
             Axis::Z => self.z * self.y.powi(3) / 12.0,
@@ -43,7 +65,18 @@ impl CrossSection for Rect {
     fn w_el(&self, axis: Axis) -> f64 {
         match axis {
             Axis::X => {
-                todo!()
+                // Roark's approximation of the largest shear stress at the middle of the long
+                // side
+                let (b, h) = (self.y.min(self.z), self.y.max(self.z));
+                let r = b / h;
+                let factor = 0.91f64.mul_add(
+                    r.powi(4),
+                    1.8023f64.mul_add(
+                        -r.powi(3),
+                        0.8865f64.mul_add(r * r, 0.6095f64.mul_add(r, 1.0)),
+                    ),
+                );
+                h * b.powi(2) / (3.0 * factor)
             }
             Axis::Y => self.I(axis) / (self.z / 2.0),
             Axis::Z => self.I(axis) / (self.y / 2.0),
@@ -51,16 +84,19 @@ impl CrossSection for Rect {
     }
     fn w_pl(&self, axis: Axis) -> f64 {
         match axis {
+            // Sand heap analogy
             Axis::X => {
-                todo!()
+                let (t, b) = (self.y.min(self.z), self.y.max(self.z));
+                t.powi(2) * 3.0f64.mul_add(b, -t) / 6.0
             }
             Axis::Y => (1.0 / 4.0) * self.width() * self.height().powi(2),
             Axis::Z => (1.0 / 4.0) * self.height() * self.width().powi(2),
         }
     }
 
+    /// Shear area of solid bars according to NS-EN 1993-1-1 6.2.6(3)
     fn area_shear(&self, _axis: Axis) -> f64 {
-        todo!()
+        self.area()
     }
 }
 
@@ -110,6 +146,7 @@ mod tests {
 
         assert_zeq!(crs.I(Axis::Y), 225_000_000.0);
         assert_zeq!(crs.I(Axis::Z), 25_000_000.0);
+        assert_zeq!(crs.I(Axis::X), 79_021_604.938_271);
     }
 
     #[test]
@@ -120,5 +157,9 @@ mod tests {
 
         assert_zeq!(crs.w_el(Axis::Y), 1_500_000.0);
         assert_zeq!(crs.w_el(Axis::Z), 500_000.0);
+        // 0.208 a^3 for a square
+        assert_zeq!(Rect::new(100.0, 100.0).w_el(Axis::X), 207_852.674);
+        // a^3 / 3 for a square when fully plastic
+        assert_zeq!(Rect::new(100.0, 100.0).w_pl(Axis::X), 333_333.333_333);
     }
 }
@@ -0,0 +1,164 @@
+use std::f64::consts::PI;
+
+use crate::Axis;
+
+use serde::{Deserialize, Serialize};
+
+use super::{circle::Circle, rect::Rect, CrossSection};
+
+/// Concrete outline of a reinforced section
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Outline {
+    Rect(Rect),
+    Circle(Circle),
+}
+
+impl Outline {
+    fn crs(&self) -> &dyn CrossSection {
+        match self {
+            Self::Rect(crs) => crs,
+            Self::Circle(crs) => crs,
+        }
+    }
+}
+
+/// Reinforcing bar placed at (y, z) from the centroid of the outline
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Rebar {
+    pub y: f64,
+    pub z: f64,
+    pub diameter: f64,
+}
+
+impl Rebar {
+    #[must_use]
+    pub const fn new(y: f64, z: f64, diameter: f64) -> Self {
+        Self { y, z, diameter }
+    }
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        PI * self.diameter.powi(2) / 4.0
+    }
+}
+
+/// Concrete section of a `Rect` or `Circle` with a layout of reinforcing bars
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Reinforced {
+    pub outline: Outline,
+    pub bars: Vec<Rebar>,
+}
+
+impl Reinforced {
+    #[must_use]
+    pub const fn new(outline: Outline, bars: Vec<Rebar>) -> Self {
+        Self { outline, bars }
+    }
+
+    /// Rectangle with `n` bars evenly spaced along both the top and the bottom face, where
+    /// `cover` is measured to the surface of the bars
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn rect(crs: Rect, n: usize, diameter: f64, cover: f64) -> Self {
+        let edge = cover + diameter / 2.0;
+        let z = crs.height() / 2.0 - edge;
+        let spacing = if n > 1 {
+            2.0f64.mul_add(-edge, crs.width()) / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let bars = (0..n)
+            .flat_map(|i| {
+                let y = if n > 1 {
+                    (i as f64).mul_add(spacing, edge - crs.width() / 2.0)
+                } else {
+                    0.0
+                };
+                [Rebar::new(y, z, diameter), Rebar::new(y, -z, diameter)]
+            })
+            .collect();
+        Self::new(Outline::Rect(crs), bars)
+    }
+
+    /// Circle with `n` bars evenly spaced around the perimeter
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn circle(crs: Circle, n: usize, diameter: f64, cover: f64) -> Self {
+        let radius = crs.width() / 2.0 - cover - diameter / 2.0;
+        let bars = (0..n)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / n as f64;
+                Rebar::new(radius * angle.cos(), radius * angle.sin(), diameter)
+            })
+            .collect();
+        Self::new(Outline::Circle(crs), bars)
+    }
+
+    /// Depth of the outline in the direction resisting moments about `axis`
+    #[must_use]
+    pub fn depth(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::Y | Axis::X => self.outline.crs().height(),
+            Axis::Z => self.outline.crs().width(),
+        }
+    }
+    /// Width of the concrete at the distance `z` from the centroid, measured along the
+    /// depth for moments about `axis`
+    #[must_use]
+    pub fn width_at(&self, z: f64, axis: Axis) -> f64 {
+        match &self.outline {
+            Outline::Rect(crs) => {
+                if z.abs() > self.depth(axis) / 2.0 {
+                    0.0
+                } else if matches!(axis, Axis::Z) {
+                    crs.height()
+                } else {
+                    crs.width()
+                }
+            }
+            Outline::Circle(crs) => {
+                let r = crs.width() / 2.0;
+                2.0 * ((r - z) * (r + z)).max(0.0).sqrt()
+            }
+        }
+    }
+    /// Distance of a bar from the centroid, measured along the depth for moments about `axis`
+    #[must_use]
+    pub const fn lever(bar: &Rebar, axis: Axis) -> f64 {
+        match axis {
+            Axis::Y | Axis::X => bar.z,
+            Axis::Z => bar.y,
+        }
+    }
+
+    #[must_use]
+    pub fn area_concrete(&self) -> f64 {
+        self.outline.crs().area()
+    }
+    #[must_use]
+    pub fn area_steel(&self) -> f64 {
+        self.bars.iter().map(Rebar::area).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn rect_layout_is_symmetric() {
+        let crs = Reinforced::rect(Rect::new(300.0, 500.0), 3, 20.0, 40.0);
+        assert_eq!(crs.bars.len(), 6);
+        assert_eq!(crs.bars[0], Rebar::new(-100.0, 200.0, 20.0));
+        assert_zeq!(crs.bars.iter().map(|bar| bar.y).sum::<f64>(), 0.0);
+        assert_zeq!(crs.area_steel(), 1_884.955_592);
+    }
+
+    #[test]
+    fn circle_layout_and_width() {
+        let crs = Reinforced::circle(Circle::new(400.0), 8, 16.0, 50.0);
+        assert_zeq!(crs.bars[2].z, 142.0);
+        assert_zeq!(crs.width_at(0.0, Axis::Y), 400.0);
+        assert_zeq!(crs.width_at(250.0, Axis::Y), 0.0);
+    }
+}
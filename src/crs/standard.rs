@@ -1,13 +1,14 @@
-use super::CrossSection;
+use super::{ibeam::IBeam, tube::Tube, CrossSection, CrsDef, Shape};
 use crate::{err::EmsError, Axis};
 use polars::prelude::*;
-use std::io::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
 
 const HEB: &[u8] = include_bytes!("./data/HEB.csv");
 const CHS: &[u8] = include_bytes!("./data/CHS.csv");
 
 #[allow(dead_code)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PRESETS {
     HEB,
     CHS,
@@ -23,6 +24,17 @@ impl PRESETS {
         }
     }
     #[must_use]
+    pub const fn all() -> [Self; 2] {
+        [Self::HEB, Self::CHS]
+    }
+    #[must_use]
+    pub const fn shape(&self) -> Shape {
+        match self {
+            Self::HEB => Shape::IBeam,
+            Self::CHS => Shape::Tube,
+        }
+    }
+    #[must_use]
     pub const fn is_symmetric(&self) -> bool {
         match self {
             Self::HEB => false,
@@ -54,66 +66,148 @@ impl PRESETS {
         buffer
     }
 }
+
+/// Canonical designation of a catalogue section, independent of how it is spelled.
+///
+/// "HEB300", "HEB 300", "HE 300 B" and "he300b" all parse to `HEB 300`, while
+/// "CHS 323.9x8", "chs323,9X8.0" and "Celsius 355 CHS 323.9x8" all parse to `CHS 323.9x8`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SectionName {
+    pub preset: PRESETS,
+    designation: String,
+}
+
+impl SectionName {
+    #[must_use]
+    pub fn parse(label: &str) -> Option<Self> {
+        let norm = normalise(label);
+
+        if let Some(pos) = norm.find("CHS") {
+            let (d, t) = norm[pos + 3..].split_once('X')?;
+            let d = d.parse::<f64>().ok()?;
+            let t = t.parse::<f64>().ok()?;
+            return Some(Self {
+                preset: PRESETS::CHS,
+                designation: format!("{d}x{t}"),
+            });
+        }
+
+        let size = norm
+            .strip_prefix("HEB")
+            .or_else(|| norm.strip_prefix("HE")?.strip_suffix('B'))?;
+        let size = size.parse::<u32>().ok()?;
+        Some(Self {
+            preset: PRESETS::HEB,
+            designation: format!("{size}"),
+        })
+    }
+
+    /// Compact form used for comparisons, e.g. "HEB300" or "CHS323.9x8"
+    #[must_use]
+    pub fn canonical(&self) -> String {
+        format!("{:?}{}", self.preset, self.designation)
+    }
+}
+
+impl std::fmt::Display for SectionName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} {}", self.preset, self.designation)
+    }
+}
+
+/// Uppercase, whitespace-free form of a label with decimal commas and
+/// multiplication signs unified
+fn normalise(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            ',' => '.',
+            '×' | '*' => 'X',
+            _ => c.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+/// Number of single character edits needed to turn `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Catalogue value that differs from the value recomputed from the plate geometry
+#[derive(Debug)]
+pub struct Deviation {
+    pub section: String,
+    pub property: &'static str,
+    pub catalogue: f64,
+    pub computed: f64,
+}
+
+impl Deviation {
+    #[must_use]
+    pub fn relative(&self) -> f64 {
+        (self.computed - self.catalogue).abs() / self.catalogue.abs()
+    }
+}
+
+impl std::fmt::Display for Deviation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} is {} in the catalogue but computes to {:.2} ({:.1}%)",
+            self.section,
+            self.property,
+            self.catalogue,
+            self.computed,
+            self.relative() * 100.0
+        )
+    }
+}
+
+/// Catalogue column, factor converting it to [mm] units and the matching computed property
+type Property = (&'static str, f64, fn(&dyn CrossSection) -> f64);
+
 pub struct CrsLib {
     df: LazyFrame,
-    is_symmetric: bool,
+    preset: PRESETS,
 }
 
 impl CrsLib {
-    /// Shit function but here we go
-    /// As far as my peanut brain can tell, there isnt an easy API in polars that allow you to create a
-    /// Embeded csv bytes are written into a file that is built into the Temp directory
-    /// The handle is then read back into polars
-    ///
-    /// Super low hanging fruit in terms of optimization for instance only create this file once per launch of the app?
+    /// Reads the embeded csv bytes of a given preset into a lazyframe
     ///
     /// # Errors
     ///
-    /// Will return an error if something goes wrong with the file write/read
-    #[allow(clippy::needless_return)]
+    /// Will return an error if polars is unable to parse the embeded csv
     pub fn new(presets: &PRESETS) -> Result<Self, EmsError> {
-        // Grab associated pathnames and bytes for a given type
-        let path = presets.path_str();
-        let bytes = presets.embeded_bytes();
-
-        // Create and write the file
-        let mut file = std::fs::File::create(&path).map_err(|e| {
-            EmsError::write_error(
-                format!("Could not create file at path: {}", &path),
-                Some(Box::new(e)),
-            )
-        })?;
-        file.write_all(bytes).map_err(|e| {
-            EmsError::write_error(
-                format!("Could not write to file at path: {}", &path),
-                Some(Box::new(e)),
-            )
-        })?;
-
         // Enforce stringtype on column 0
         let mut s = Schema::default();
         s.coerce_by_index(0, DataType::Utf8);
 
-        // Read file and return lazyframe
-        let df = CsvReader::new(std::fs::File::open(&path).map_err(|e| {
-            EmsError::file_not_found_error(
-                format!("Could not read file at path: {}", &path),
-                Some(Box::new(e)),
-            )
-        })?)
-        .with_delimiter(b',')
-        .has_header(true)
-        .with_dtypes(Some(&s))
-        .finish()
-        .map_err(|e| {
-            EmsError::file_not_found_error(
-                format!("Could not read file at path: {}", &path),
-                Some(Box::new(e)),
-            )
-        })?;
+        let df = CsvReader::new(Cursor::new(presets.embeded_bytes()))
+            .with_delimiter(b',')
+            .has_header(true)
+            .with_dtypes(Some(&s))
+            .finish()
+            .map_err(|e| {
+                EmsError::database_error(
+                    format!("Could not read embeded csv for {presets:?}"),
+                    Some(Box::new(e)),
+                )
+            })?;
         Ok(Self {
             df: df.lazy(),
-            is_symmetric: presets.is_symmetric(),
+            preset: *presets,
         })
     }
 
@@ -144,12 +238,163 @@ impl CrsLib {
             .collect();
         Ok(series)
     }
+
+    /// Recomputes area, inertia and section moduli of every row from its plate geometry and
+    /// reports the rows where the catalogue deviates by more than the relative `tolerance`
+    ///
+    /// # Errors
+    /// Will return error if something is wrong with the polars dataframe navigation or reading
+    pub fn validate(&self, tolerance: f64) -> Result<Vec<Deviation>, EmsError> {
+        let df = self.df.clone().collect().map_err(|e| {
+            EmsError::database_error("Could not clone self.df".to_owned(), Some(Box::new(e)))
+        })?;
+        let sections = self.sections()?;
+
+        let geometry: Vec<Box<dyn CrossSection>> = match self.preset {
+            PRESETS::HEB => {
+                let h = Self::column_values(&df, "h[mm]")?;
+                let b = Self::column_values(&df, "b[mm]")?;
+                let tw = Self::column_values(&df, "tw[mm]")?;
+                let tf = Self::column_values(&df, "tf[mm]")?;
+                let r = Self::column_values(&df, "r1[mm]")?;
+                (0..df.height())
+                    .map(|i| {
+                        Box::new(IBeam::new(h[i], b[i], tw[i], tf[i], r[i]))
+                            as Box<dyn CrossSection>
+                    })
+                    .collect()
+            }
+            PRESETS::CHS => {
+                let d = Self::column_values(&df, "d[mm]")?;
+                let t = Self::column_values(&df, "t[mm]")?;
+                (0..df.height())
+                    .map(|i| Box::new(Tube::new(d[i], t[i])) as Box<dyn CrossSection>)
+                    .collect()
+            }
+        };
+
+        let mut properties: Vec<Property> = vec![
+            ("A[cm2]", 1e2, |crs| crs.area()),
+            ("Iy[cm4]", 1e4, |crs| crs.I(Axis::Y)),
+            ("Wy[cm3]", 1e3, |crs| crs.w_el(Axis::Y)),
+            ("Wpl,y[cm3]", 1e3, |crs| crs.w_pl(Axis::Y)),
+        ];
+        if !self.preset.is_symmetric() {
+            properties.extend_from_slice(&[
+                ("Iz[cm4]", 1e4, |crs| crs.I(Axis::Z)),
+                ("Wz[cm3]", 1e3, |crs| crs.w_el(Axis::Z)),
+                ("Wpl,z[cm3]", 1e3, |crs| crs.w_pl(Axis::Z)),
+            ]);
+        }
+
+        let mut deviations = Vec::new();
+        for (property, factor, compute) in properties {
+            let values = Self::column_values(&df, property)?;
+            for ((section, crs), value) in sections.iter().zip(&geometry).zip(values) {
+                let deviation = Deviation {
+                    section: section.clone(),
+                    property,
+                    catalogue: value,
+                    computed: compute(crs.as_ref()) / factor,
+                };
+                if deviation.relative() > tolerance {
+                    deviations.push(deviation);
+                }
+            }
+        }
+        Ok(deviations)
+    }
+
+    fn column_values(data: &DataFrame, key: &str) -> Result<Vec<f64>, EmsError> {
+        let series = data
+            .column(key)
+            .map_err(|e| {
+                EmsError::database_error(format!("Could not get column {key}"), Some(Box::new(e)))
+            })?
+            .cast(&DataType::Float64)
+            .map_err(|e| {
+                EmsError::database_error(
+                    format!("Could not convert column {key} into f64"),
+                    Some(Box::new(e)),
+                )
+            })?;
+        let values = series
+            .f64()
+            .map_err(|e| {
+                EmsError::database_error(
+                    format!("Could not convert column {key} into f64"),
+                    Some(Box::new(e)),
+                )
+            })?
+            .into_iter()
+            .map(|value| value.unwrap_or(f64::NAN))
+            .collect();
+        Ok(values)
+    }
+
+    /// Resolves a loosely spelled label, e.g. "HE 300 B" or "CHS 323.9x8", to the exact
+    /// section name used in the catalogue
+    ///
+    /// # Errors
+    /// Will return a `SectionNotFound` error listing the closest catalogue names if the label
+    /// does not match any section
+    pub fn find(&self, label: &str) -> Result<String, EmsError> {
+        let sections = self.sections()?;
+        if sections.iter().any(|section| section == label) {
+            return Ok(label.to_owned());
+        }
+
+        let wanted = SectionName::parse(label);
+        if let Some(wanted) = &wanted {
+            if let Some(section) = sections
+                .iter()
+                .find(|section| SectionName::parse(section).as_ref() == Some(wanted))
+            {
+                return Ok(section.clone());
+            }
+        }
+
+        // Compare the canonical forms where possible so the catalogue prefix doesnt dominate
+        let query = wanted.map_or_else(|| normalise(label), |name| name.canonical().to_uppercase());
+        let mut ranked: Vec<(usize, &String)> = sections
+            .iter()
+            .map(|section| {
+                let key = SectionName::parse(section).map_or_else(
+                    || normalise(section),
+                    |name| name.canonical().to_uppercase(),
+                );
+                (edit_distance(&query, &key), section)
+            })
+            .collect();
+        ranked.sort_by_key(|(distance, _)| *distance);
+        let suggestions: Vec<&str> = ranked
+            .iter()
+            .take(3)
+            .map(|(_, section)| section.as_str())
+            .collect();
+
+        Err(EmsError::section_not_found_error(
+            format!(
+                "Could not find section \"{label}\" among {:?} sections, did you mean: {}?",
+                self.preset,
+                suggestions.join(", ")
+            ),
+            None,
+        ))
+    }
 }
 
+/// Catalogue section with its tabulated properties
+///
+/// Serialises the properties rather than a reference into the catalogue, so a saved section does
+/// not change if the catalogue is later corrected
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PresetCrs {
+    section: String,
     width: f64,
     height: f64,
     area: f64,
+    thickness: f64,
     area_shear_y: f64,
     area_shear_z: f64,
     inertia_y: f64,
@@ -158,21 +403,40 @@ pub struct PresetCrs {
     inertia_z: f64,
     w_elastic_z: f64,
     w_plastic_z: f64,
+    inertia_t: f64,
+    warping: f64,
+    w_torsion: f64,
+    web: Option<(f64, f64)>,
+    flange: Option<(f64, f64)>,
+    shape: Shape,
+    section_factor: f64,
+    shadow_factor: f64,
 }
 
 impl PresetCrs {
+    /// Looks up a section in the given library, see [`CrsLib::find`] for accepted spellings
+    ///
     /// # Errors
-    /// Will return an error if it cannot clone the lazyframe with mask
+    /// Will return an error if the section is not in the library, or if it cannot clone the
+    /// lazyframe with mask
     pub fn new(label: &str, lib: &CrsLib) -> Result<Self, EmsError> {
-        // HELLA TRASH FUNCTION, PLEASE FIX
-        let mask = col("Section").eq(lit(label));
+        let section = lib.find(label)?;
+        let mask = col("Section").eq(lit(section.as_str()));
         let temp = lib.df.clone().filter(mask).collect().map_err(|e| {
             EmsError::database_error("Could not clone self.df".to_owned(), Some(Box::new(e)))
         })?;
+        if temp.height() != 1 {
+            return Err(EmsError::database_error(
+                format!("Expected one row named {section}, found {}", temp.height()),
+                None,
+            ));
+        }
 
-        let out = if lib.is_symmetric {
+        let out = if lib.preset.is_symmetric() {
             Self {
+                section,
                 area: Self::read_value(&temp, "A[cm2]")? * 1e2,
+                thickness: Self::read_value(&temp, "t[mm]")?,
                 area_shear_y: Self::read_value(&temp, "Ay[cm2]")? * 1e2,
                 area_shear_z: Self::read_value(&temp, "Ay[cm2]")? * 1e2,
                 width: Self::read_value(&temp, "d[mm]")?,
@@ -183,12 +447,32 @@ impl PresetCrs {
                 inertia_z: Self::read_value(&temp, "Iy[cm4]")? * 1e4,
                 w_elastic_z: Self::read_value(&temp, "Wy[cm3]")? * 1e3,
                 w_plastic_z: Self::read_value(&temp, "Wpl,y[cm3]")? * 1e3,
+                inertia_t: Self::read_value(&temp, "It[cm4]")? * 1e4,
+                warping: 0.0,
+                w_torsion: Self::read_value(&temp, "Wt[cm3]")? * 1e3,
+                web: None,
+                flange: None,
+                shape: lib.preset.shape(),
+                section_factor: Self::read_value(&temp, "Am/V[1/m]")?,
+                shadow_factor: 1.0,
             }
         } else {
+            let area = Self::read_value(&temp, "A[cm2]")? * 1e2;
+            let (b, tf) = (
+                Self::read_value(&temp, "b[mm]")?,
+                Self::read_value(&temp, "tf[mm]")?,
+            );
+            let (tw, r) = (
+                Self::read_value(&temp, "tw[mm]")?,
+                Self::read_value(&temp, "r1[mm]")?,
+            );
             Self {
-                area: Self::read_value(&temp, "A[cm2]")? * 1e2,
+                section,
+                area,
+                thickness: tf.max(tw),
                 area_shear_y: Self::read_value(&temp, "Ay[cm2]")? * 1e2,
-                area_shear_z: Self::read_value(&temp, "Az[cm2]")? * 1e2,
+                // The catalogue Az is the shear deformation area, so use 6.2.6(3) a) instead
+                area_shear_z: (2.0 * b).mul_add(-tf, 2.0f64.mul_add(r, tw).mul_add(tf, area)),
                 width: Self::read_value(&temp, "b[mm]")?,
                 height: Self::read_value(&temp, "h[mm]")?,
                 inertia_y: Self::read_value(&temp, "Iy[cm4]")? * 1e4,
@@ -197,24 +481,78 @@ impl PresetCrs {
                 inertia_z: Self::read_value(&temp, "Iz[cm4]")? * 1e4,
                 w_elastic_z: Self::read_value(&temp, "Wz[cm3]")? * 1e3,
                 w_plastic_z: Self::read_value(&temp, "Wpl,z[cm3]")? * 1e3,
+                inertia_t: Self::read_value(&temp, "It[cm4]")? * 1e4,
+                warping: Self::read_value(&temp, "Iω[cm6]")? * 1e6,
+                w_torsion: Self::read_value(&temp, "Wt[cm3]")? * 1e3,
+                web: Some((
+                    Self::read_value(&temp, "hi[mm]")?,
+                    Self::read_value(&temp, "tw[mm]")?,
+                )),
+                flange: Some((
+                    Self::read_value(&temp, "b[mm]")?,
+                    Self::read_value(&temp, "tf[mm]")?,
+                )),
+                shape: lib.preset.shape(),
+                section_factor: Self::read_value(&temp, "Am/V[1/m]")?,
+                shadow_factor: 1.0,
+            }
+        };
+        let out = if lib.preset.is_symmetric() {
+            out
+        } else {
+            // 0.9 of the box value over the catalogue section factor, NS-EN 1993-1-2 4.26a
+            let box_value = 2000.0 * (out.width + out.height) / out.area;
+            Self {
+                shadow_factor: 0.9 * box_value / out.section_factor,
+                ..out
             }
         };
         Ok(out)
     }
 
+    /// Looks up a section by name alone, inferring the catalogue from the designation
+    ///
+    /// # Errors
+    /// Will return an error if the label cannot be recognised as a catalogue section, or if the
+    /// section is not found
+    pub fn from_name(label: &str) -> Result<Self, EmsError> {
+        let name = SectionName::parse(label).ok_or_else(|| {
+            EmsError::section_not_found_error(
+                format!(
+                    "Could not recognise \"{label}\" as any of {:?}",
+                    PRESETS::all()
+                ),
+                None,
+            )
+        })?;
+        Self::new(label, &CrsLib::new(&name.preset)?)
+    }
+
+    /// Name of the section as written in the catalogue
+    #[must_use]
+    pub fn section(&self) -> &str {
+        &self.section
+    }
+
     fn read_value(data: &DataFrame, key: &str) -> Result<f64, EmsError> {
         data.column(key)
             .map_err(|e| {
-                EmsError::database_error(format!("Could not get column {}", key), Some(Box::new(e)))
+                EmsError::database_error(format!("Could not get column {key}"), Some(Box::new(e)))
             })?
             .sum::<f64>()
             .ok_or_else(|| {
-                EmsError::database_error(format!("Could not get value in column {}", key), None)
+                EmsError::database_error(format!("Could not get value in column {key}"), None)
             })
     }
 }
 
 impl CrossSection for PresetCrs {
+    fn definition(&self) -> CrsDef {
+        CrsDef::Preset(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        self.shape
+    }
     fn width(&self) -> f64 {
         self.width
     }
@@ -224,30 +562,43 @@ impl CrossSection for PresetCrs {
     fn area(&self) -> f64 {
         self.area
     }
+    fn t_max(&self) -> f64 {
+        self.thickness
+    }
+    fn section_factor(&self) -> f64 {
+        self.section_factor
+    }
+    fn shadow_factor(&self) -> f64 {
+        self.shadow_factor
+    }
+    fn web(&self) -> Option<(f64, f64)> {
+        self.web
+    }
+    fn flange(&self) -> Option<(f64, f64)> {
+        self.flange
+    }
 
     fn I(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => self.inertia_t,
             Axis::Y => self.inertia_y,
             Axis::Z => self.inertia_z,
         }
     }
+    fn I_w(&self) -> f64 {
+        self.warping
+    }
     fn w_el(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => self.w_torsion,
             Axis::Y => self.w_elastic_y,
             Axis::Z => self.w_elastic_z,
         }
     }
+    /// The catalogues do not give the plastic torsional modulus, so the elastic one is used
     fn w_pl(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => self.w_torsion,
             Axis::Y => self.w_plastic_y,
             Axis::Z => self.w_plastic_z,
         }
@@ -255,9 +606,7 @@ impl CrossSection for PresetCrs {
 
     fn area_shear(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => self.area,
             Axis::Y => self.area_shear_y,
             Axis::Z => self.area_shear_z,
         }
@@ -276,10 +625,98 @@ mod tests {
             .expect("Couldnt create crs");
         assert_zeq!(7_940.0, crs.area());
     }
+    #[test]
+    fn section_factor_is_read_from_catalogue() {
+        let crs = PresetCrs::from_name("HEB 300").expect("Couldnt create crs");
+        assert_zeq!(crs.section_factor(), 116.11);
+        assert!(crs.shadow_factor() < 1.0);
+        let crs = PresetCrs::from_name("CHS 219.1x5").expect("Couldnt create crs");
+        assert_zeq!(crs.section_factor(), 204.76);
+        assert_zeq!(crs.shadow_factor(), 1.0);
+    }
+
+    #[test]
+    fn shear_area_of_rolled_sections() {
+        // A - 2 b t_f + (t_w + 2 r) t_f rather than the 30.83 cm2 in the Az column
+        let crs = PresetCrs::from_name("HEB 300").expect("Couldnt create crs");
+        assert_zeq!(crs.area_shear(Axis::Z), 4_735.0);
+    }
+
     #[test]
     fn can_collect_vector_from_section_names() {
         let df = CrsLib::new(&PRESETS::CHS).expect("Couldnt create df");
         let res = df.sections().expect("Couldnt get the sections");
         dbg!(res);
     }
+
+    #[test]
+    fn parses_canonical_section_names() {
+        let expected = SectionName::parse("HEB 300").expect("Couldnt parse name");
+        for label in ["HEB300", "HE 300 B", "he300b", "HEB 300"] {
+            assert_eq!(SectionName::parse(label), Some(expected.clone()));
+        }
+        assert_eq!(expected.canonical(), "HEB300");
+
+        let expected = SectionName::parse("Celsius 355 CHS 323.9x8").expect("Couldnt parse name");
+        for label in ["CHS 323.9x8", "chs323,9X8.0", "CHS 323.9 x 8"] {
+            assert_eq!(SectionName::parse(label), Some(expected.clone()));
+        }
+        assert_eq!(expected.to_string(), "CHS 323.9x8");
+
+        assert_eq!(SectionName::parse("HEB"), None);
+        assert_eq!(SectionName::parse("IPE 300"), None);
+    }
+
+    #[test]
+    fn finds_section_from_loose_label() {
+        let lib = CrsLib::new(&PRESETS::HEB).expect("Couldnt create df");
+        assert_eq!(lib.find("HE 300 B").expect("Couldnt find"), "HEB 300");
+
+        let crs = PresetCrs::from_name("CHS 323.9x8").expect("Couldnt create crs");
+        assert_zeq!(7_940.0, crs.area());
+    }
+
+    #[test]
+    fn missing_section_is_an_error_with_suggestions() {
+        let lib = CrsLib::new(&PRESETS::HEB).expect("Couldnt create df");
+        let err = PresetCrs::new("HEB 310", &lib).expect_err("HEB 310 should not exist");
+        let message = err.to_string();
+        assert!(message.contains("HEB 300"), "{message}");
+        assert!(message.contains("HEB 320"), "{message}");
+
+        assert!(PresetCrs::from_name("Celsius 355 CHS 323.9x9").is_err());
+        assert!(PresetCrs::from_name("not a section").is_err());
+    }
+
+    #[test]
+    fn catalogues_agree_with_computed_geometry() {
+        for preset in PRESETS::all() {
+            let lib = CrsLib::new(&preset).expect("Couldnt create df");
+            let deviations = lib.validate(0.02).expect("Couldnt validate");
+            let rows: Vec<String> = deviations.iter().map(ToString::to_string).collect();
+            assert!(
+                deviations.is_empty(),
+                "{preset:?} has suspicious rows:\n{}",
+                rows.join("\n")
+            );
+        }
+    }
+
+    #[test]
+    fn validation_reports_rows_outside_tolerance() {
+        let lib = CrsLib::new(&PRESETS::HEB).expect("Couldnt create df");
+        let deviations = lib.validate(0.0).expect("Couldnt validate");
+        assert!(!deviations.is_empty());
+        assert!(deviations
+            .iter()
+            .all(|deviation| deviation.relative() > 0.0));
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("HEB300", "HEB300"), 0);
+        assert_eq!(edit_distance("HEB310", "HEB300"), 1);
+        assert_eq!(edit_distance("HEB30", "HEB300"), 1);
+        assert_eq!(edit_distance("", "HEB"), 3);
+    }
 }
@@ -0,0 +1,166 @@
+use crate::Axis;
+
+use serde::{Deserialize, Serialize};
+
+use super::{CrossSection, CrsDef, Shape};
+
+/// T-section of plates with the flange on top, symmetric about the z-axis and neglecting the
+/// root fillet
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Tee {
+    h: f64,
+    b: f64,
+    tw: f64,
+    tf: f64,
+}
+
+impl Tee {
+    #[must_use]
+    pub const fn new(h: f64, b: f64, tw: f64, tf: f64) -> Self {
+        Self { h, b, tw, tf }
+    }
+
+    /// Height of the stem below the flange
+    fn hw(&self) -> f64 {
+        self.h - self.tf
+    }
+
+    /// Height of the centroid above the tip of the stem
+    fn z_c(&self) -> f64 {
+        let flange = self.b * self.tf * (self.h - self.tf / 2.0);
+        let stem = self.tw * self.hw().powi(2) / 2.0;
+        (flange + stem) / self.area()
+    }
+}
+
+impl Default for Tee {
+    fn default() -> Self {
+        Self::new(150.0, 150.0, 10.0, 12.0)
+    }
+}
+
+impl CrossSection for Tee {
+    fn definition(&self) -> CrsDef {
+        CrsDef::Tee(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        Shape::Tee
+    }
+    fn width(&self) -> f64 {
+        self.b
+    }
+    fn height(&self) -> f64 {
+        self.h
+    }
+    fn area(&self) -> f64 {
+        self.b.mul_add(self.tf, self.hw() * self.tw)
+    }
+    fn t_max(&self) -> f64 {
+        self.tf.max(self.tw)
+    }
+    fn centroid(&self) -> (f64, f64) {
+        (self.b / 2.0, self.z_c())
+    }
+    /// At the intersection of the midlines of the flange and the stem
+    fn shear_centre(&self) -> (f64, f64) {
+        (0.0, self.h - self.tf / 2.0 - self.z_c())
+    }
+    fn web(&self) -> Option<(f64, f64)> {
+        Some((self.hw(), self.tw))
+    }
+    fn flange(&self) -> Option<(f64, f64)> {
+        Some((self.b, self.tf))
+    }
+
+    #[allow(non_snake_case)]
+    fn I(&self, axis: Axis) -> f64 {
+        match axis {
+            // Thin-walled open section
+            Axis::X => self.b.mul_add(self.tf.powi(3), self.hw() * self.tw.powi(3)) / 3.0,
+            Axis::Y => {
+                // Each plate about its own centroid plus the Steiner term
+                let e_f = self.h - self.tf / 2.0 - self.z_c();
+                let e_w = self.hw() / 2.0 - self.z_c();
+                let flange = self.b * self.tf * e_f.mul_add(e_f, self.tf.powi(2) / 12.0);
+                let stem = self.tw * self.hw() * e_w.mul_add(e_w, self.hw().powi(2) / 12.0);
+                flange + stem
+            }
+            Axis::Z => self.tf.mul_add(self.b.powi(3), self.hw() * self.tw.powi(3)) / 12.0,
+        }
+    }
+
+    /// Flange and stem each warping about their own midline
+    #[allow(non_snake_case)]
+    fn I_w(&self) -> f64 {
+        (self.b * self.tf).powi(3) / 144.0 + (self.tw * (self.h - self.tf / 2.0)).powi(3) / 36.0
+    }
+
+    fn w_el(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.I(axis) / self.t_max(),
+            // At the tip of the stem or the top of the flange, whichever is further away
+            Axis::Y => self.I(axis) / self.z_c().max(self.h - self.z_c()),
+            Axis::Z => self.I(axis) / (self.b / 2.0),
+        }
+    }
+
+    fn w_pl(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.b.mul_add(self.tf.powi(2), self.hw() * self.tw.powi(2)) / 2.0,
+            Axis::Y => {
+                let half = self.area() / 2.0;
+                if self.b * self.tf >= half {
+                    // Plastic neutral axis in the flange, at the depth `d` from the top
+                    let d = half / self.b;
+                    let flange = self.b * d.mul_add(d, (self.tf - d).powi(2)) / 2.0;
+                    (self.tw * self.hw()).mul_add(self.hw() / 2.0 + self.tf - d, flange)
+                } else {
+                    // Plastic neutral axis in the stem, at the depth `d` from the top
+                    let d = self.tf + self.b.mul_add(-self.tf, half) / self.tw;
+                    let stem =
+                        self.tw * (d - self.tf).mul_add(d - self.tf, (self.h - d).powi(2)) / 2.0;
+                    self.b.mul_add(self.tf * (d - self.tf / 2.0), stem)
+                }
+            }
+            Axis::Z => self.b.powi(2).mul_add(self.tf, self.hw() * self.tw.powi(2)) / 4.0,
+        }
+    }
+
+    /// Shear areas of welded T-sections according to NS-EN 1993-1-1 6.2.6(3)
+    fn area_shear(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.area(),
+            Axis::Y => self.b * self.tf,
+            Axis::Z => self.tw * (self.h - self.tf / 2.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn section_properties() {
+        let crs = Tee::default();
+        assert_zeq!(crs.area(), 3180.0);
+        assert_zeq!(crs.centroid().1, 111.452_830);
+        assert_zeq!(crs.shear_centre().1, 32.547_170);
+        assert_zeq!(crs.I(Axis::Y), 6_605_527.924_528);
+        assert_zeq!(crs.I(Axis::Z), 3_386_500.0);
+        assert_zeq!(crs.I(Axis::X), 132_400.0);
+        assert_zeq!(crs.I_w(), 123_444_000.0);
+    }
+
+    #[test]
+    fn section_moduli() {
+        let crs = Tee::default();
+        assert_zeq!(crs.w_el(Axis::Y), 59_267.475_876);
+        assert_zeq!(crs.w_pl(Axis::Y), 105_726.0);
+        assert_zeq!(crs.w_pl(Axis::Z), 70_950.0);
+        // Deep stems move the plastic neutral axis below the flange
+        let crs = Tee::new(300.0, 100.0, 10.0, 10.0);
+        assert_zeq!(crs.w_pl(Axis::Y), 335_250.0);
+    }
+}
@@ -2,8 +2,11 @@ use std::f64::consts::PI;
 
 use crate::Axis;
 
-use super::CrossSection;
+use serde::{Deserialize, Serialize};
 
+use super::{CrossSection, CrsDef, Shape};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Tube {
     d: f64,
     t: f64,
@@ -30,6 +33,12 @@ impl Default for Tube {
 }
 
 impl CrossSection for Tube {
+    fn definition(&self) -> CrsDef {
+        CrsDef::Tube(self.clone())
+    }
+    fn shape(&self) -> Shape {
+        Shape::Tube
+    }
     fn width(&self) -> f64 {
         self.d
     }
@@ -39,36 +48,40 @@ impl CrossSection for Tube {
     fn area(&self) -> f64 {
         PI * (self.r().powi(2) - self.r_inner().powi(2))
     }
+    fn section_factor(&self) -> f64 {
+        1000.0 * PI * self.d / self.area()
+    }
+    fn t_max(&self) -> f64 {
+        self.t
+    }
+    fn width_at(&self, z: f64, _axis: Axis) -> Option<f64> {
+        let chord = |r: f64| 2.0 * ((r - z) * (r + z)).max(0.0).sqrt();
+        Some(chord(self.r()) - chord(self.r_inner()))
+    }
 
     fn I(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => PI / 2.0 * (self.r().powi(4) - self.r_inner().powi(4)),
             Axis::Y | Axis::Z => PI / 4.0 * (self.r().powi(4) - self.r_inner().powi(4)),
         }
     }
 
     fn w_el(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
-            Axis::Y | Axis::Z => self.I(axis) / self.r(),
+            Axis::X | Axis::Y | Axis::Z => self.I(axis) / self.r(),
         }
     }
 
     fn w_pl(&self, axis: Axis) -> f64 {
         match axis {
-            Axis::X => {
-                todo!()
-            }
+            Axis::X => 2.0 * PI / 3.0 * (self.r().powi(3) - self.r_inner().powi(3)),
             Axis::Y | Axis::Z => (4.0 / 3.0) * (self.r().powi(3) - self.r_inner().powi(3)),
         }
     }
 
+    /// Shear area of circular hollow sections according to NS-EN 1993-1-1 6.2.6(3)
     fn area_shear(&self, _axis: Axis) -> f64 {
-        todo!()
+        2.0 * self.area() / PI
     }
 }
 
@@ -0,0 +1,111 @@
+/// Stefan Boltzmann constant in W/m^2 K^4
+const SIGMA: f64 = 5.67e-8;
+
+/// Net heat flux to a surface in W/m^2, 3.1, with the configuration factor 1.0, the
+/// emissivity of the fire 1.0 and `epsilon_m` of the member surface
+#[must_use]
+pub fn f_3_1(alpha_c: f64, epsilon_m: f64, theta_g: f64, theta_m: f64) -> f64 {
+    let radiation = epsilon_m * SIGMA * ((theta_g + 273.0).powi(4) - (theta_m + 273.0).powi(4));
+    alpha_c.mul_add(theta_g - theta_m, radiation)
+}
+
+/// Standard temperature-time curve (ISO 834) at `t` minutes, 3.2.1
+#[must_use]
+pub fn f_3_4(t: f64) -> f64 {
+    345.0f64.mul_add(8.0f64.mul_add(t, 1.0).log10(), 20.0)
+}
+
+/// External fire curve at `t` minutes, 3.2.2
+#[must_use]
+pub fn f_3_5(t: f64) -> f64 {
+    660.0f64.mul_add(
+        0.313f64.mul_add(-(-3.8 * t).exp(), 0.687f64.mul_add(-(-0.32 * t).exp(), 1.0)),
+        20.0,
+    )
+}
+
+/// Hydrocarbon curve at `t` minutes, 3.2.3
+#[must_use]
+pub fn f_3_6(t: f64) -> f64 {
+    1080.0f64.mul_add(
+        0.675f64.mul_add(
+            -(-2.5 * t).exp(),
+            0.325f64.mul_add(-(-0.167 * t).exp(), 1.0),
+        ),
+        20.0,
+    )
+}
+
+/// Heating phase of the parametric fire at the fictitious time `t_star` in hours, A.1
+#[must_use]
+pub fn f_a_1(t_star: f64) -> f64 {
+    let decay = 0.472f64.mul_add(
+        (-19.0 * t_star).exp(),
+        0.204f64.mul_add((-1.7 * t_star).exp(), 0.324 * (-0.2 * t_star).exp()),
+    );
+    1325.0f64.mul_add(1.0 - decay, 20.0)
+}
+
+/// Time factor Γ of the parametric fire, A.2a, for the opening factor `o` in m^0.5 and the
+/// thermal absorptivity `b` in J/m^2 s^0.5 K
+#[must_use]
+pub fn f_a_2a(o: f64, b: f64) -> f64 {
+    (o / b).powi(2) / (0.04f64 / 1160.0).powi(2)
+}
+
+/// Cooling phase of the parametric fire, A.11, where `x` is 1.0 for ventilation controlled
+/// fires and `t_lim` Γ / `t_star_max` for fuel controlled fires
+#[must_use]
+pub fn f_a_11(theta_max: f64, t_star: f64, t_star_max: f64, x: f64) -> f64 {
+    let elapsed = t_star_max.mul_add(-x, t_star);
+    let rate = if t_star_max <= 0.5 {
+        625.0
+    } else if t_star_max < 2.0 {
+        250.0 * (3.0 - t_star_max)
+    } else {
+        250.0
+    };
+    rate.mul_add(-elapsed, theta_max).max(20.0)
+}
+
+/// Convective heat transfer coefficient in W/m^2 K, NS-EN 1991-1-2 3.2
+pub mod alpha_c {
+    /// Standard and external fire curves, 3.2.1(2) and 3.2.2(2)
+    pub const STANDARD: f64 = 25.0;
+    /// Hydrocarbon curve, 3.2.3(2)
+    pub const HYDROCARBON: f64 = 50.0;
+    /// Parametric fire, Annex A(2)
+    pub const PARAMETRIC: f64 = 35.0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn net_heat_flux() {
+        assert_zeq!(f_3_1(25.0, 0.7, 20.0, 20.0), 0.0);
+        assert_zeq!((f_3_1(25.0, 0.7, 842.0, 20.0) / 1e3).round(), 82.0);
+    }
+
+    #[test]
+    fn nominal_curves() {
+        assert_zeq!(f_3_4(0.0), 20.0);
+        assert_zeq!(f_3_4(30.0).round(), 842.0);
+        assert_zeq!(f_3_4(60.0).round(), 945.0);
+        assert_zeq!(f_3_5(0.0), 20.0);
+        assert_zeq!(f_3_5(1e3).round(), 680.0);
+        assert_zeq!(f_3_6(1e3).round(), 1100.0);
+    }
+
+    #[test]
+    fn parametric_curve() {
+        // Γ = 1 follows the standard curve closely
+        assert_zeq!(f_a_2a(0.04, 1160.0), 1.0);
+        assert_zeq!(f_a_1(0.0), 20.0);
+        assert_zeq!(f_a_1(1.0).round(), 944.0);
+        assert_zeq!(f_a_11(900.0, 1.0, 1.0, 1.0), 900.0);
+        assert_zeq!(f_a_11(900.0, 10.0, 1.0, 1.0), 20.0);
+    }
+}
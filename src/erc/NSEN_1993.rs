@@ -1,3 +1,6 @@
+use crate::crs::{Fabrication, Shape};
+use crate::Axis;
+
 #[allow(non_snake_case)]
 
 /// Design check 6.2 for columnbeam
@@ -6,6 +9,64 @@ pub fn f_6_2(N_ed: f64, My_ed: f64, Mz_ed: f64, N_rd: f64, My_rd: f64, Mz_rd: f6
     N_ed / N_rd + My_ed / My_rd + Mz_ed / Mz_rd
 }
 
+/*----------------- CROSS-SECTION RESISTANCE ------------------*/
+
+/// Plastic resistance of the gross cross-section, 6.6 and 6.10
+#[must_use]
+pub fn f_6_6(area: f64, fy: f64, gamma_m0: f64) -> f64 {
+    area * fy / gamma_m0
+}
+
+/// Ultimate resistance of the net cross-section at holes for fasteners, 6.7
+#[must_use]
+pub fn f_6_7(area_net: f64, fu: f64, gamma_m2: f64) -> f64 {
+    0.9 * area_net * fu / gamma_m2
+}
+
+/// Plastic moment resistance of class 1 and 2 cross-sections, 6.13
+#[must_use]
+pub fn f_6_13(w_pl: f64, fy: f64, gamma_m0: f64) -> f64 {
+    w_pl * fy / gamma_m0
+}
+
+/// Plastic shear resistance, 6.18
+#[must_use]
+pub fn f_6_18(area_v: f64, fy: f64, gamma_m0: f64) -> f64 {
+    area_v * fy / (3f64.sqrt() * gamma_m0)
+}
+
+/// Whether a web without intermediate stiffeners must be checked for shear buckling, 6.22
+#[must_use]
+pub fn f_6_22(h_w: f64, t_w: f64, epsilon: f64, eta: f64) -> bool {
+    h_w / t_w > 72.0 * epsilon / eta
+}
+
+/// Shear resistance of an I or H section reduced by the torsional shear stress `tau_t`, 6.26
+#[must_use]
+pub fn f_6_26(tau_t: f64, fy: f64, gamma_m0: f64, v_pl_rd: f64) -> f64 {
+    let f_v = fy / (3f64.sqrt() * gamma_m0);
+    (1.0 - tau_t / (1.25 * f_v)).max(0.0).sqrt() * v_pl_rd
+}
+
+/// Shear resistance of a structural hollow section reduced by the torsional shear stress
+/// `tau_t`, 6.28
+#[must_use]
+pub fn f_6_28(tau_t: f64, fy: f64, gamma_m0: f64, v_pl_rd: f64) -> f64 {
+    let f_v = fy / (3f64.sqrt() * gamma_m0);
+    (1.0 - tau_t / f_v).max(0.0) * v_pl_rd
+}
+
+/// Reduction factor for the yield strength of the shear area, 6.2.8(3), zero when the shear
+/// force does not exceed half the plastic shear resistance
+#[must_use]
+pub fn rho(v_ed: f64, v_pl_rd: f64) -> f64 {
+    if v_ed <= 0.5 * v_pl_rd {
+        0.0
+    } else {
+        ((2.0 * v_ed) / v_pl_rd - 1.0).powi(2).min(1.0)
+    }
+}
+
 /// Reduced capacity to account for shear forces
 /// Not necessary to reduce if Ved < 0.5 Vrd
 #[must_use]
@@ -13,6 +74,92 @@ pub fn f_6_29(fy: f64, rho: f64) -> f64 {
     (1.0 - rho) * fy
 }
 
+/// Plastic moment resistance about the major axis of an I-section with equal flanges reduced
+/// for shear, 6.30, where `area_w` is `h_w t_w`
+#[must_use]
+pub fn f_6_30(w_pl: f64, rho: f64, area_w: f64, t_w: f64, fy: f64, gamma_m0: f64) -> f64 {
+    (rho * area_w.powi(2)).mul_add(-1.0 / (4.0 * t_w), w_pl) * fy / gamma_m0
+}
+
+/*----------------- BENDING AND AXIAL FORCE ------------------*/
+// `n` is the ratio N_Ed / N_pl,Rd in the reduced plastic moments of 6.2.9.1
+
+/// Plastic moment resistance of a rectangular solid section reduced for axial force, 6.32
+#[must_use]
+pub fn f_6_32(m_pl_rd: f64, n: f64) -> f64 {
+    m_pl_rd * n.mul_add(-n, 1.0).max(0.0)
+}
+
+/// Whether the axial force leaves the plastic moment of an I-section about the y-axis
+/// unreduced, 6.33 and 6.34, where `n_w_rd` is `h_w t_w f_y / gamma_m0`
+#[must_use]
+pub fn f_6_33(n_ed: f64, n_pl_rd: f64, n_w_rd: f64) -> bool {
+    n_ed <= 0.25 * n_pl_rd && n_ed <= 0.5 * n_w_rd
+}
+
+/// Whether the axial force leaves the plastic moment of an I-section about the z-axis
+/// unreduced, 6.35
+#[must_use]
+pub fn f_6_35(n_ed: f64, n_w_rd: f64) -> bool {
+    n_ed <= n_w_rd
+}
+
+/// Reduced plastic moment of an I-section about the y-axis, 6.36, where `a` is
+/// `(A - 2 b t_f) / A`
+#[must_use]
+pub fn f_6_36(m_pl_y_rd: f64, n: f64, a: f64) -> f64 {
+    (m_pl_y_rd * (1.0 - n).max(0.0) / 0.5f64.mul_add(-a.min(0.5), 1.0)).min(m_pl_y_rd)
+}
+
+/// Reduced plastic moment of an I-section about the z-axis, 6.37 and 6.38
+#[must_use]
+pub fn f_6_38(m_pl_z_rd: f64, n: f64, a: f64) -> f64 {
+    let a = a.min(0.5);
+    if n <= a {
+        m_pl_z_rd
+    } else {
+        let ratio = ((n - a) / (1.0 - a)).min(1.0);
+        m_pl_z_rd * ratio.mul_add(-ratio, 1.0)
+    }
+}
+
+/// Reduced plastic moment of a rectangular hollow section of uniform thickness, 6.39 about
+/// the y-axis with `a` = `a_w` = `(A - 2 b t) / A`, and 6.40 about the z-axis with `a` =
+/// `a_f` = `(A - 2 h t) / A`
+#[must_use]
+pub fn f_6_39(m_pl_rd: f64, n: f64, a: f64) -> f64 {
+    (m_pl_rd * (1.0 - n).max(0.0) / 0.5f64.mul_add(-a.min(0.5), 1.0)).min(m_pl_rd)
+}
+
+/// Reduced plastic moment of a circular hollow section, `M_pl,Rd (1 - n^1.7)`, which the
+/// Eurocode leaves to the designer in 6.2.9.1(5)
+#[must_use]
+pub fn m_n_chs(m_pl_rd: f64, n: f64) -> f64 {
+    m_pl_rd * (1.0 - n.powf(1.7)).max(0.0)
+}
+
+/// Bi-axial bending with axial force, 6.41, giving the utilisation
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn f_6_41(my_ed: f64, m_n_y_rd: f64, mz_ed: f64, m_n_z_rd: f64, alpha: f64, beta: f64) -> f64 {
+    (my_ed / m_n_y_rd).powf(alpha) + (mz_ed / m_n_z_rd).powf(beta)
+}
+
+/// Exponents alpha and beta of 6.41, 6.2.9.1(6), conservatively unity for shapes the clause
+/// does not cover
+#[must_use]
+pub fn f_6_41_exponents(shape: Shape, n: f64) -> (f64, f64) {
+    match shape {
+        Shape::IBeam => (2.0, (5.0 * n).max(1.0)),
+        Shape::Tube => (2.0, 2.0),
+        Shape::Box => {
+            let exponent = (1.66 / (1.13 * n).mul_add(-n, 1.0)).clamp(1.0, 6.0);
+            (exponent, exponent)
+        }
+        Shape::Rect | Shape::Circle | Shape::Angle | Shape::Tee => (1.0, 1.0),
+    }
+}
+
 /// Reduction factor rho for shear when computing moment
 #[must_use]
 pub fn _compute_rho(Ved: f64, Vpl_rd: f64) -> f64 {
@@ -46,6 +193,40 @@ pub fn _compute_lamba(area: f64, fy: f64, n_cr: f64) -> f64 {
     (area * fy / n_cr).sqrt()
 }
 
+/// Elastic critical force for torsional buckling with the torsional buckling length `l_t`,
+/// where `i_0` is the polar radius of gyration about the shear centre, NS-EN 1993-1-3 6.33a
+#[must_use]
+pub fn n_cr_t(i_0: f64, gi_t: f64, ei_w: f64, l_t: f64) -> f64 {
+    (std::f64::consts::PI / l_t).powi(2).mul_add(ei_w, gi_t) / i_0.powi(2)
+}
+
+/// Elastic critical force for torsional-flexural buckling, NS-EN 1993-1-3 6.35 for sections
+/// symmetric about one axis
+///
+/// Taken as the lowest root of the buckling determinant coupling the flexural forces `n_cr`
+/// about y and z with `n_cr_t`, for the shear centre at `offset` from the centroid
+#[must_use]
+pub fn n_cr_tf(n_cr: (f64, f64), n_cr_t: f64, offset: (f64, f64), i_0: f64) -> f64 {
+    let ((n_cr_y, n_cr_z), (y_0, z_0)) = (n_cr, offset);
+    let (a_y, a_z) = ((y_0 / i_0).powi(2), (z_0 / i_0).powi(2));
+    let determinant = |n: f64| {
+        let coupling = n.powi(2) * a_y.mul_add(n - n_cr_z, a_z * (n - n_cr_y));
+        ((n - n_cr_y) * (n - n_cr_z)).mul_add(n - n_cr_t, -coupling)
+    };
+    // Negative without load and not negative at the lowest uncoupled force
+    let (mut low, mut high) = (0.0, n_cr_y.min(n_cr_z).min(n_cr_t));
+    for _ in 0..100 {
+        let mid = 0.5 * (low + high);
+        if determinant(mid) < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    0.5 * (low + high)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BuckleCurve {
     A0,
     A,
@@ -78,6 +259,318 @@ impl BuckleCurve {
     }
 }
 
+/// Flexural buckling curve for buckling about `axis`, Table 6.2
+///
+/// `t_f` is the flange thickness of I-sections and `s460` selects the column of grade S460,
+/// whatever the yield strength is for the thickness. Welded boxes are taken with welds of
+/// throat thickness up to half the flange thickness
+#[must_use]
+pub fn buckling_curve(
+    shape: Shape,
+    fabrication: Fabrication,
+    h_over_b: f64,
+    t_f: f64,
+    s460: bool,
+    axis: Axis,
+) -> BuckleCurve {
+    let major = axis != Axis::Z;
+    match (shape, fabrication) {
+        (Shape::IBeam, Fabrication::Rolled) => match (h_over_b > 1.2, t_f) {
+            (true, t_f) if t_f <= 40.0 => match (s460, major) {
+                (true, _) => BuckleCurve::A0,
+                (false, true) => BuckleCurve::A,
+                (false, false) => BuckleCurve::B,
+            },
+            (_, t_f) if t_f <= 100.0 => match (s460, major) {
+                (true, _) => BuckleCurve::A,
+                (false, true) => BuckleCurve::B,
+                (false, false) => BuckleCurve::C,
+            },
+            _ if s460 => BuckleCurve::C,
+            _ => BuckleCurve::D,
+        },
+        (Shape::IBeam, _) => match (t_f <= 40.0, major) {
+            (true, true) => BuckleCurve::B,
+            (true, false) | (false, true) => BuckleCurve::C,
+            (false, false) => BuckleCurve::D,
+        },
+        (Shape::Tube | Shape::Box, Fabrication::Rolled) if s460 => BuckleCurve::A0,
+        (Shape::Tube | Shape::Box, Fabrication::Rolled) => BuckleCurve::A,
+        (Shape::Box, Fabrication::Welded) | (Shape::Angle, _) => BuckleCurve::B,
+        _ => BuckleCurve::C,
+    }
+}
+
+/*----------------- LATERAL TORSIONAL BUCKLING ------------------*/
+
+/// Elastic critical moment by the three factor formula of ENV 1993-1-1 Annex F, F.2
+///
+/// `c` holds C1, C2 and C3 for the moment diagram, `k` and `k_w` are the effective length
+/// factors for lateral bending and warping, `z_g` is the height of the load above the shear
+/// centre, positive when destabilising, and `z_j` is zero for doubly symmetric sections
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn m_cr(
+    c: (f64, f64, f64),
+    k: f64,
+    k_w: f64,
+    length: f64,
+    ei_z: f64,
+    gi_t: f64,
+    i_w_over_i_z: f64,
+    z_g: f64,
+    z_j: f64,
+) -> f64 {
+    let (c1, c2, c3) = c;
+    let kl = k * length;
+    let euler = std::f64::consts::PI.powi(2) * ei_z / kl.powi(2);
+    let height = c2.mul_add(z_g, -c3 * z_j);
+    let root = height.mul_add(
+        height,
+        (k / k_w).powi(2).mul_add(i_w_over_i_z, gi_t / euler),
+    );
+    c1 * euler * (root.sqrt() - height)
+}
+
+/// Design buckling resistance moment, 6.55
+#[must_use]
+pub fn f_6_55(khi_lt: f64, w_y: f64, fy: f64, gamma_m1: f64) -> f64 {
+    khi_lt * w_y * fy / gamma_m1
+}
+
+/// Reduction factor for lateral torsional buckling, general case, 6.56
+#[must_use]
+pub fn f_6_56(alpha_lt: f64, lambda_lt: f64) -> f64 {
+    let phi = 0.5 * lambda_lt.mul_add(lambda_lt, alpha_lt.mul_add(lambda_lt - 0.2, 1.0));
+    (1.0 / (phi + phi.mul_add(phi, -lambda_lt.powi(2)).sqrt())).min(1.0)
+}
+
+/// Reduction factor for lateral torsional buckling of rolled or equivalent welded sections,
+/// 6.57
+#[must_use]
+pub fn f_6_57(alpha_lt: f64, lambda_lt: f64, lambda_lt0: f64, beta: f64) -> f64 {
+    let phi =
+        0.5 * (beta * lambda_lt).mul_add(lambda_lt, alpha_lt.mul_add(lambda_lt - lambda_lt0, 1.0));
+    let khi = 1.0 / (phi + phi.mul_add(phi, -beta * lambda_lt.powi(2)).sqrt());
+    khi.min(1.0).min(1.0 / lambda_lt.powi(2))
+}
+
+/// Modified reduction factor for the moment distribution between lateral restraints, 6.58
+#[must_use]
+pub fn f_6_58(khi_lt: f64, k_c: f64, lambda_lt: f64) -> f64 {
+    let f = (0.5 * (1.0 - k_c))
+        .mul_add(-2.0f64.mul_add(-(lambda_lt - 0.8).powi(2), 1.0), 1.0)
+        .min(1.0);
+    (khi_lt / f).min(1.0).min(1.0 / lambda_lt.powi(2))
+}
+
+/// Lateral torsional buckling curve for the general case, Table 6.4
+#[must_use]
+pub fn ltb_curve(shape: Shape, fabrication: Fabrication, h_over_b: f64) -> BuckleCurve {
+    match (shape, fabrication, h_over_b > 2.0) {
+        (Shape::IBeam, Fabrication::Rolled, false) => BuckleCurve::A,
+        (Shape::IBeam, Fabrication::Rolled, true) => BuckleCurve::B,
+        (Shape::IBeam, _, false) => BuckleCurve::C,
+        _ => BuckleCurve::D,
+    }
+}
+
+/// Bending moment diagram between lateral restraints, giving the factors of the critical
+/// moment and the correction factor `k_c`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MomentDiagram {
+    /// End moments M and `psi` M
+    EndMoments { psi: f64 },
+    /// Uniformly distributed load on a simply supported span
+    UniformLoad,
+    /// Uniformly distributed load with both ends fixed
+    FixedUniformLoad,
+    /// Point load at midspan of a simply supported span
+    PointLoad,
+    /// Point load at midspan with both ends fixed
+    FixedPointLoad,
+    /// Equal point loads at the quarter points of a simply supported span
+    QuarterPointLoads,
+}
+
+impl MomentDiagram {
+    /// C1, C2 and C3 for the effective length factor `k`, from Table F.1.1 for end moments,
+    /// tabulated for k = 1 and conservative for smaller k, and from Table F.1.2 for transverse
+    /// loads, interpolated between k = 1 and k = 0.5
+    #[must_use]
+    pub fn factors(&self, k: f64) -> (f64, f64, f64) {
+        let between = |fixed: (f64, f64, f64), free: (f64, f64, f64)| {
+            let t = ((1.0 - k) / 0.5).clamp(0.0, 1.0);
+            (
+                t.mul_add(fixed.0 - free.0, free.0),
+                t.mul_add(fixed.1 - free.1, free.1),
+                t.mul_add(fixed.2 - free.2, free.2),
+            )
+        };
+        match self {
+            Self::EndMoments { psi } => {
+                const C1: [f64; 9] = [1.0, 1.141, 1.323, 1.563, 1.879, 2.281, 2.704, 2.927, 2.752];
+                const C3: [f64; 9] = [1.0, 0.998, 0.992, 0.977, 0.939, 0.855, 0.676, 0.366, 0.0];
+                let x = 4.0 * (1.0 - psi.clamp(-1.0, 1.0));
+                let i = x.floor().min(7.0);
+                let t = x - i;
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let i = i as usize;
+                (
+                    t.mul_add(C1[i + 1] - C1[i], C1[i]),
+                    0.0,
+                    t.mul_add(C3[i + 1] - C3[i], C3[i]),
+                )
+            }
+            Self::UniformLoad => between((0.972, 0.304, 0.980), (1.132, 0.459, 0.525)),
+            Self::FixedUniformLoad => between((0.712, 0.652, 1.070), (1.285, 1.562, 0.753)),
+            Self::PointLoad => between((1.070, 0.432, 3.050), (1.365, 0.553, 1.730)),
+            Self::FixedPointLoad => between((0.938, 0.715, 4.800), (1.565, 1.267, 2.640)),
+            Self::QuarterPointLoads => between((1.010, 0.410, 1.890), (1.046, 0.430, 1.120)),
+        }
+    }
+    /// Equivalent uniform moment factor of Annex B, Table B.3
+    #[must_use]
+    pub const fn c_m(&self) -> f64 {
+        match self {
+            Self::EndMoments { psi } => 0.4f64.mul_add(psi.clamp(-1.0, 1.0), 0.6).max(0.4),
+            Self::UniformLoad | Self::QuarterPointLoads => 0.95,
+            Self::PointLoad => 0.90,
+            // End moments M_h with the midspan moment M_s = -M_h / 2, 0.1 - 0.8 alpha_s
+            Self::FixedUniformLoad => 0.5,
+            // End moments M_h with the midspan moment M_s = -M_h, -0.8 alpha_s
+            Self::FixedPointLoad => 0.8,
+        }
+    }
+    /// Equivalent uniform moment factor `C_mi,0` of Annex A, Table A.2, for the ratio
+    /// `n_ed / n_cr` about the same axis. Transverse loads use the general expression with the
+    /// midspan deflection of the diagram
+    #[must_use]
+    pub fn c_m0(&self, n_ratio: f64) -> f64 {
+        let pi2 = std::f64::consts::PI.powi(2);
+        // pi^2 E I delta / (L^2 M) for the largest moment and deflection
+        let deflection = match self {
+            Self::EndMoments { psi } => {
+                let psi = psi.clamp(-1.0, 1.0);
+                return (0.36 * (psi - 0.33)).mul_add(n_ratio, 0.21f64.mul_add(psi, 0.79));
+            }
+            Self::UniformLoad => pi2 * 5.0 / 48.0,
+            Self::FixedUniformLoad => pi2 / 32.0,
+            Self::PointLoad => pi2 / 12.0,
+            Self::FixedPointLoad => pi2 / 24.0,
+            Self::QuarterPointLoads => pi2 * 11.0 / 96.0,
+        };
+        (deflection - 1.0).mul_add(n_ratio, 1.0)
+    }
+    /// Correction factor for the moment distribution, Table 6.6, conservatively 1.0 for
+    /// diagrams it does not list
+    #[must_use]
+    pub fn k_c(&self) -> f64 {
+        match self {
+            Self::EndMoments { psi } => 1.0 / 0.33f64.mul_add(-psi.clamp(-1.0, 1.0), 1.33),
+            Self::UniformLoad => 0.94,
+            Self::FixedUniformLoad => 0.90,
+            Self::PointLoad => 0.86,
+            Self::FixedPointLoad => 0.77,
+            Self::QuarterPointLoads => 1.0,
+        }
+    }
+}
+
+/*----------------- BENDING AND AXIAL COMPRESSION ------------------*/
+// `n` is N_Ed / (khi N_Rk / gamma_M1) about the relevant axis, `m_y` is
+// M_y,Ed / (khi_LT M_y,Rk / gamma_M1) and `m_z` is M_z,Ed / (M_z,Rk / gamma_M1)
+
+/// Interaction of flexural buckling about the y-axis with bending, 6.61
+#[allow(clippy::similar_names)]
+#[must_use]
+pub const fn f_6_61(n: f64, m_y: f64, m_z: f64, k_yy: f64, k_yz: f64) -> f64 {
+    k_yz.mul_add(m_z, k_yy.mul_add(m_y, n))
+}
+
+/// Interaction of flexural buckling about the z-axis with bending, 6.62
+#[allow(clippy::similar_names)]
+#[must_use]
+pub const fn f_6_62(n: f64, m_y: f64, m_z: f64, k_zy: f64, k_zz: f64) -> f64 {
+    k_zz.mul_add(m_z, k_zy.mul_add(m_y, n))
+}
+
+/// Source of the interaction factors of 6.61 and 6.62
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InteractionMethod {
+    /// Method 1, Annex A
+    AnnexA,
+    /// Method 2, Annex B
+    AnnexB,
+}
+
+/// Interaction factors `(k_yy, k_yz, k_zy, k_zz)` of class 1 and 2 sections by Annex B, Table
+/// B.1, or Table B.2 for members susceptible to torsional deformations
+///
+/// `n_y` and `n_z` are the ratios of 6.61 and 6.62 and `c_m` holds `C_my`, `C_mz` and
+/// `C_mLT`. `i_section` selects the `k_zz` of I-sections over that of rectangular hollow
+/// sections
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn annex_b_factors(
+    lambda: (f64, f64),
+    n_y: f64,
+    n_z: f64,
+    c_m: (f64, f64, f64),
+    i_section: bool,
+    torsional: bool,
+) -> (f64, f64, f64, f64) {
+    let ((lambda_y, lambda_z), (c_my, c_mz, c_mlt)) = (lambda, c_m);
+    let k_yy = c_my
+        * (lambda_y - 0.2)
+            .mul_add(n_y, 1.0)
+            .min(0.8f64.mul_add(n_y, 1.0));
+    let k_zz = if i_section {
+        c_mz * 2.0f64
+            .mul_add(lambda_z, -0.6)
+            .mul_add(n_z, 1.0)
+            .min(1.4f64.mul_add(n_z, 1.0))
+    } else {
+        c_mz * (lambda_z - 0.2)
+            .mul_add(n_z, 1.0)
+            .min(0.8f64.mul_add(n_z, 1.0))
+    };
+    let k_zy = if torsional {
+        let reduction = |lambda: f64| 1.0 - 0.1 * lambda * n_z / (c_mlt - 0.25);
+        if lambda_z < 0.4 {
+            (0.6 + lambda_z).min(reduction(lambda_z))
+        } else {
+            reduction(lambda_z).max(reduction(1.0))
+        }
+    } else {
+        0.6 * k_yy
+    };
+    (k_yy, 0.6 * k_zz, k_zy, k_zz)
+}
+
+/*----------------- CLASSIFICATION ------------------*/
+
+/// Material factor epsilon, Table 5.2
+#[must_use]
+pub fn epsilon(fy: f64) -> f64 {
+    (235.0 / fy).sqrt()
+}
+
+/// Compression parts of Table 5.2
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionPart {
+    /// Internal part, e.g. a web, in bending
+    InternalBending,
+    /// Internal part, e.g. a web, in compression
+    InternalCompression,
+    /// Outstand flange in compression
+    OutstandCompression,
+    /// Tubular section in bending
+    TubeBending,
+    /// Tubular section in compression
+    TubeCompression,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,6 +582,46 @@ mod tests {
         assert_zeq!(util, 1.0);
     }
     #[test]
+    fn net_section_rupture() {
+        assert_zeq!(f_6_7(1000.0, 490.0, 1.25), 352_800.0);
+    }
+    #[test]
+    fn shear_buckling_limit() {
+        // 72 * 0.813 / 1.2 = 48.8 for S355
+        assert!(!f_6_22(262.0, 11.0, epsilon(355.0), 1.2));
+        assert!(f_6_22(600.0, 10.0, epsilon(355.0), 1.2));
+    }
+    #[test]
+    fn torsion_reduces_shear_resistance() {
+        let tau_rd = 355.0 / 3f64.sqrt();
+        assert_zeq!(f_6_26(0.0, 355.0, 1.0, 1000.0), 1000.0);
+        assert_zeq!(f_6_26(0.25 * tau_rd, 355.0, 1.0, 1000.0), 894.427_191);
+        assert_zeq!(f_6_28(0.25 * tau_rd, 355.0, 1.0, 1000.0), 750.0);
+    }
+    #[test]
+    fn shear_reduction_factor() {
+        assert_zeq!(rho(50.0, 100.0), 0.0);
+        assert_zeq!(rho(75.0, 100.0), 0.25);
+        assert_zeq!(f_6_30(1000.0, 1.0, 100.0, 10.0, 1.0, 1.0), 750.0);
+    }
+    #[test]
+    fn reduced_plastic_moments() {
+        assert_zeq!(f_6_32(100.0, 0.5), 75.0);
+        assert_zeq!(f_6_36(100.0, 0.1, 0.4), 100.0);
+        assert_zeq!(f_6_36(100.0, 0.5, 0.4), 62.5);
+        assert_zeq!(f_6_38(100.0, 0.3, 0.4), 100.0);
+        assert_zeq!(f_6_38(100.0, 0.7, 0.4), 75.0);
+        assert_zeq!(m_n_chs(100.0, 1.0), 0.0);
+    }
+    #[test]
+    fn biaxial_exponents_depend_on_shape() {
+        assert_eq!(f_6_41_exponents(Shape::IBeam, 0.1), (2.0, 1.0));
+        assert_zeq!(f_6_41_exponents(Shape::IBeam, 0.4).1, 2.0);
+        assert_zeq!(f_6_41_exponents(Shape::Box, 0.5).0, 2.313_588);
+        assert_zeq!(f_6_41_exponents(Shape::Box, 0.9).0, 6.0);
+        assert_zeq!(f_6_41(50.0, 100.0, 50.0, 100.0, 2.0, 2.0), 0.5);
+    }
+    #[test]
     pub fn test_f_6_47() {
         let khi = 0.6;
         let area = 1000.0;
@@ -106,6 +639,148 @@ mod tests {
         assert_zeq!(f_6_49(0.2, 0.0), 1.0);
     }
 
+    #[test]
+    fn buckling_curves_follow_table_6_2() {
+        let curve = |shape, fabrication, h_over_b, t_f, s460, axis| {
+            buckling_curve(shape, fabrication, h_over_b, t_f, s460, axis)
+        };
+        // HEA 300 and HEB 300
+        assert_eq!(
+            curve(
+                Shape::IBeam,
+                Fabrication::Rolled,
+                0.97,
+                14.0,
+                false,
+                Axis::Y
+            ),
+            BuckleCurve::B
+        );
+        assert_eq!(
+            curve(Shape::IBeam, Fabrication::Rolled, 1.0, 19.0, false, Axis::Z),
+            BuckleCurve::C
+        );
+        // IPE 300
+        assert_eq!(
+            curve(Shape::IBeam, Fabrication::Rolled, 2.0, 10.7, false, Axis::Y),
+            BuckleCurve::A
+        );
+        assert_eq!(
+            curve(Shape::IBeam, Fabrication::Rolled, 2.0, 10.7, true, Axis::Z),
+            BuckleCurve::A0
+        );
+        assert_eq!(
+            curve(
+                Shape::IBeam,
+                Fabrication::Rolled,
+                1.0,
+                110.0,
+                false,
+                Axis::Y
+            ),
+            BuckleCurve::D
+        );
+        assert_eq!(
+            curve(Shape::IBeam, Fabrication::Welded, 2.0, 50.0, true, Axis::Z),
+            BuckleCurve::D
+        );
+        assert_eq!(
+            curve(Shape::Tube, Fabrication::Rolled, 1.0, 0.0, false, Axis::Y),
+            BuckleCurve::A
+        );
+        assert_eq!(
+            curve(Shape::Box, Fabrication::ColdFormed, 1.0, 0.0, true, Axis::Y),
+            BuckleCurve::C
+        );
+        assert_eq!(
+            curve(Shape::Angle, Fabrication::Rolled, 1.0, 0.0, false, Axis::Z),
+            BuckleCurve::B
+        );
+        assert_eq!(
+            curve(Shape::Rect, Fabrication::Rolled, 1.0, 0.0, false, Axis::Z),
+            BuckleCurve::C
+        );
+    }
+
+    #[test]
+    fn moment_diagram_factors() {
+        let (c1, c2, c3) = MomentDiagram::EndMoments { psi: 1.0 }.factors(1.0);
+        assert_zeq!(c1, 1.0);
+        assert_zeq!(c2, 0.0);
+        assert_zeq!(c3, 1.0);
+        assert_zeq!(
+            MomentDiagram::EndMoments { psi: -0.4 }.factors(1.0).0,
+            2.534_8
+        );
+        assert_zeq!(
+            MomentDiagram::EndMoments { psi: -1.0 }.factors(1.0).0,
+            2.752
+        );
+        assert_zeq!(MomentDiagram::UniformLoad.factors(0.5).0, 0.972);
+        assert_zeq!(MomentDiagram::UniformLoad.factors(0.75).0, 1.052);
+        assert_zeq!(MomentDiagram::EndMoments { psi: 0.0 }.k_c(), 0.751_879);
+    }
+    #[test]
+    fn correction_factors_follow_table_6_6() {
+        assert_zeq!(MomentDiagram::EndMoments { psi: 1.0 }.k_c(), 1.0);
+        assert_zeq!(MomentDiagram::EndMoments { psi: -1.0 }.k_c(), 0.602_410);
+        assert_zeq!(MomentDiagram::UniformLoad.k_c(), 0.94);
+        assert_zeq!(MomentDiagram::FixedUniformLoad.k_c(), 0.90);
+        assert_zeq!(MomentDiagram::PointLoad.k_c(), 0.86);
+        assert_zeq!(MomentDiagram::FixedPointLoad.k_c(), 0.77);
+    }
+    #[test]
+    fn equivalent_uniform_moment_factors() {
+        assert_zeq!(MomentDiagram::EndMoments { psi: 0.5 }.c_m(), 0.8);
+        assert_zeq!(MomentDiagram::EndMoments { psi: -1.0 }.c_m(), 0.4);
+        assert_zeq!(MomentDiagram::UniformLoad.c_m(), 0.95);
+        assert_zeq!(MomentDiagram::EndMoments { psi: 1.0 }.c_m0(0.1), 1.024_12);
+        assert_zeq!(MomentDiagram::UniformLoad.c_m0(0.1), 1.002_808);
+        assert_zeq!(MomentDiagram::UniformLoad.c_m0(0.0), 1.0);
+    }
+    #[allow(clippy::similar_names)]
+    #[test]
+    fn annex_b_interaction_factors() {
+        let (k_yy, k_yz, k_zy, k_zz) =
+            annex_b_factors((1.0, 0.5), 0.5, 0.5, (1.0, 1.0, 1.0), true, false);
+        assert_zeq!(k_yy, 1.4);
+        assert_zeq!(k_zz, 1.2);
+        assert_zeq!(k_yz, 0.72);
+        assert_zeq!(k_zy, 0.84);
+        let torsional = annex_b_factors((1.0, 0.5), 0.5, 0.5, (1.0, 1.0, 1.0), true, true);
+        assert_zeq!(torsional.2, 0.966_667);
+        let stocky = annex_b_factors((1.0, 0.3), 0.5, 0.5, (1.0, 1.0, 1.0), true, true);
+        assert_zeq!(stocky.2, 0.9);
+        assert_zeq!(f_6_61(0.5, 0.2, 0.1, k_yy, k_yz), 0.852);
+        assert_zeq!(f_6_62(0.5, 0.2, 0.1, k_zy, k_zz), 0.788);
+    }
+    #[test]
+    fn ltb_reduction_factors() {
+        assert_zeq!(f_6_56(0.21, 0.2), 1.0);
+        // 6.57 is capped by 1 / lambda^2
+        assert_zeq!(f_6_57(0.34, 2.0, 0.4, 0.75), 0.25);
+        assert_zeq!(f_6_58(0.8, 1.0, 1.0), 0.8);
+        assert!(f_6_58(0.8, 0.94, 1.0) > 0.8);
+        assert_eq!(
+            ltb_curve(Shape::IBeam, Fabrication::Welded, 3.0),
+            BuckleCurve::D
+        );
+    }
+    #[test]
+    fn torsional_flexural_buckling() {
+        assert_zeq!(n_cr_t(2.0, 100.0, 0.0, 1000.0), 25.0);
+        // Singly symmetric sections follow 6.35 of NS-EN 1993-1-3
+        assert_zeq!(
+            n_cr_tf((1000.0, 2000.0), 800.0, (30.0, 0.0), 60.0),
+            588.989_907
+        );
+        assert_zeq!(
+            n_cr_tf((2000.0, 1000.0), 800.0, (0.0, 30.0), 60.0),
+            588.989_907
+        );
+        // Without an offset the lowest uncoupled force governs
+        assert_zeq!(n_cr_tf((1000.0, 2000.0), 800.0, (0.0, 0.0), 60.0), 800.0);
+    }
     #[test]
     fn test_buckle_curve_function() {
         assert_zeq!(BuckleCurve::A0.alpha(), 0.13);
@@ -0,0 +1,315 @@
+/// Reduction factors (temperature, `k_y`, `k_p`, `k_E`) for carbon steel, Table 3.1
+const TABLE_3_1: [(f64, f64, f64, f64); 13] = [
+    (20.0, 1.000, 1.000, 1.000),
+    (100.0, 1.000, 1.000, 1.000),
+    (200.0, 1.000, 0.807, 0.900),
+    (300.0, 1.000, 0.613, 0.800),
+    (400.0, 1.000, 0.420, 0.700),
+    (500.0, 0.780, 0.360, 0.600),
+    (600.0, 0.470, 0.180, 0.310),
+    (700.0, 0.230, 0.075, 0.130),
+    (800.0, 0.110, 0.050, 0.090),
+    (900.0, 0.060, 0.0375, 0.0675),
+    (1000.0, 0.040, 0.0250, 0.0450),
+    (1100.0, 0.020, 0.0125, 0.0225),
+    (1200.0, 0.000, 0.0000, 0.0000),
+];
+
+/// Strain at the start of the yield plateau, 3.2.2
+pub const EPS_Y: f64 = 0.02;
+/// Strain at the end of the yield plateau, 3.2.2
+pub const EPS_T: f64 = 0.15;
+/// Ultimate strain, 3.2.2
+pub const EPS_U: f64 = 0.20;
+
+/// Linear interpolation in Table 3.1, as allowed by 3.2.1(3)
+fn interpolate(theta: f64, column: fn(&(f64, f64, f64, f64)) -> f64) -> f64 {
+    let theta = theta.clamp(20.0, 1200.0);
+    let upper = TABLE_3_1
+        .iter()
+        .position(|row| row.0 >= theta)
+        .unwrap_or(TABLE_3_1.len() - 1)
+        .max(1);
+    let (low, high) = (&TABLE_3_1[upper - 1], &TABLE_3_1[upper]);
+    let ratio = (theta - low.0) / (high.0 - low.0);
+    ratio.mul_add(column(high) - column(low), column(low))
+}
+
+/// Reduction factor for the effective yield strength at the steel temperature `theta` in
+/// degrees Celsius, Table 3.1
+#[must_use]
+pub fn k_y_theta(theta: f64) -> f64 {
+    interpolate(theta, |row| row.1)
+}
+
+/// Reduction factor for the proportional limit, Table 3.1
+#[must_use]
+pub fn k_p_theta(theta: f64) -> f64 {
+    interpolate(theta, |row| row.2)
+}
+
+/// Reduction factor for the slope of the linear elastic range, Table 3.1
+#[must_use]
+pub fn k_E_theta(theta: f64) -> f64 {
+    interpolate(theta, |row| row.3)
+}
+
+/// Stress for a given strain at elevated temperature, Table 3.1 and Figure 3.1, from the
+/// reduced yield strength, proportional limit and modulus of elasticity
+#[must_use]
+pub fn stress(strain: f64, f_y: f64, f_p: f64, e_a: f64) -> f64 {
+    let strain = strain.abs();
+    let eps_p = f_p / e_a;
+    if strain <= eps_p {
+        return strain * e_a;
+    }
+    if strain < EPS_Y {
+        let c = (f_y - f_p).powi(2) / (EPS_Y - eps_p).mul_add(e_a, -2.0 * (f_y - f_p));
+        let a = ((EPS_Y - eps_p) * (EPS_Y - eps_p + c / e_a)).sqrt();
+        let b = (c * (EPS_Y - eps_p)).mul_add(e_a, c.powi(2)).sqrt();
+        return (b / a).mul_add(a.mul_add(a, -(EPS_Y - strain).powi(2)).sqrt(), f_p - c);
+    }
+    if strain <= EPS_T {
+        return f_y;
+    }
+    if strain < EPS_U {
+        return f_y * (1.0 - (strain - EPS_T) / (EPS_U - EPS_T));
+    }
+    0.0
+}
+
+/*------------- THERMAL PROPERTIES -------------*/
+
+/// Thermal elongation of carbon steel, the relative length change from 20 degrees Celsius,
+/// 3.1a to 3.1c
+#[must_use]
+pub fn f_3_1(theta: f64) -> f64 {
+    if theta < 750.0 {
+        0.4e-8f64.mul_add(theta.powi(2), 1.2e-5f64.mul_add(theta, -2.416e-4))
+    } else if theta <= 860.0 {
+        1.1e-2
+    } else {
+        2e-5f64.mul_add(theta, -6.2e-3)
+    }
+}
+
+/// Specific heat of carbon steel in J/kg K at the temperature `theta`, 3.4.1.2
+#[must_use]
+pub fn specific_heat(theta: f64) -> f64 {
+    let theta = theta.clamp(20.0, 1200.0);
+    if theta < 600.0 {
+        2.22e-6f64.mul_add(
+            theta.powi(3),
+            (-1.69e-3f64).mul_add(theta.powi(2), 0.773f64.mul_add(theta, 425.0)),
+        )
+    } else if theta < 735.0 {
+        666.0 + 13_002.0 / (738.0 - theta)
+    } else if theta < 900.0 {
+        545.0 + 17_820.0 / (theta - 731.0)
+    } else {
+        650.0
+    }
+}
+
+/*------------- TEMPERATURE DEVELOPMENT ------------*/
+
+/// Temperature increase of unprotected steel during `dt` seconds, 4.2.5.1, for the shadow
+/// factor `k_sh`, section factor `am_v` in 1/m and net heat flux `h_net` in W/m^2
+#[must_use]
+pub fn f_4_25(k_sh: f64, am_v: f64, c_a: f64, rho_a: f64, h_net: f64, dt: f64) -> f64 {
+    k_sh * am_v / (c_a * rho_a) * h_net * dt
+}
+
+/// Temperature increase of insulated steel during `dt` seconds, 4.2.5.2, for the
+/// conductivity `lambda_p`, section factor `ap_v` in 1/m and thickness `d_p` in m of the
+/// protection, where `phi` is from 4.28
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn f_4_27(
+    lambda_p: f64,
+    ap_v: f64,
+    d_p: f64,
+    c_a: f64,
+    rho_a: f64,
+    phi: f64,
+    theta_g: f64,
+    theta_a: f64,
+    d_theta_g: f64,
+    dt: f64,
+) -> f64 {
+    let heating = lambda_p * ap_v * (theta_g - theta_a) / (d_p * c_a * rho_a * (1.0 + phi / 3.0));
+    let increase = heating.mul_add(dt, -(phi / 10.0).exp_m1() * d_theta_g);
+    if d_theta_g > 0.0 {
+        increase.max(0.0)
+    } else {
+        increase
+    }
+}
+
+/// Ratio of the heat capacity of the protection to that of the steel, 4.28
+#[must_use]
+pub fn f_4_28(c_p: f64, rho_p: f64, d_p: f64, ap_v: f64, c_a: f64, rho_a: f64) -> f64 {
+    c_p * rho_p / (c_a * rho_a) * d_p * ap_v
+}
+
+/*------------- STRUCTURAL FIRE DESIGN -------------*/
+
+/// Imperfection factor of the fire buckling curve, 4.2.3.2(2)
+#[must_use]
+pub fn alpha(f_y: f64) -> f64 {
+    0.65 * (235.0 / f_y).sqrt()
+}
+
+/// Non-dimensional slenderness at the steel temperature `theta`, 4.7, also used for lateral
+/// torsional buckling in 4.15
+#[must_use]
+pub fn f_4_7(lambda: f64, theta: f64) -> f64 {
+    lambda * (k_y_theta(theta) / k_E_theta(theta)).sqrt()
+}
+
+/// Reduction factor for flexural buckling in the fire design situation, 4.6, also used for
+/// lateral torsional buckling in 4.13
+#[must_use]
+pub fn f_4_6(lambda_theta: f64, alpha: f64) -> f64 {
+    let phi = 0.5 * lambda_theta.mul_add(lambda_theta, alpha.mul_add(lambda_theta, 1.0));
+    (1.0 / (phi + phi.mul_add(phi, -lambda_theta.powi(2)).sqrt())).min(1.0)
+}
+
+/// Equivalent uniform moment factor for a linear moment diagram with end moment ratio `psi`,
+/// Table 4.2
+#[must_use]
+pub fn beta_m(psi: f64) -> f64 {
+    0.7f64.mul_add(-psi, 1.8)
+}
+
+/// Slenderness at `theta` where the slenderness at 20 degrees Celsius is limited to 1.1, as
+/// required by 4.2.3.5(1), scaling it with 4.7
+fn lambda_limited(lambda_20: f64, lambda_theta: f64) -> f64 {
+    lambda_theta * (1.1 / lambda_20).min(1.0)
+}
+
+/// Interaction factor `k_y` for flexural buckling about the y-axis, 4.2.3.5(1)
+///
+/// For the compression `n_ed` and the buckling resistance `n_b_y` about the same axis, with
+/// the slenderness `lambda_y_20` at 20 degrees Celsius limited to 1.1
+#[must_use]
+pub fn k_y_fi(beta_m_y: f64, lambda_y_20: f64, lambda_y_theta: f64, n_ed: f64, n_b_y: f64) -> f64 {
+    let mu = 1.2f64
+        .mul_add(beta_m_y, -3.0)
+        .mul_add(
+            lambda_limited(lambda_y_20, lambda_y_theta),
+            0.44f64.mul_add(beta_m_y, -0.29),
+        )
+        .min(0.8);
+    (1.0 - mu * n_ed / n_b_y).min(3.0)
+}
+
+/// Interaction factor `k_z` for flexural buckling about the z-axis, 4.2.3.5(1), with the
+/// slenderness `lambda_z_20` at 20 degrees Celsius limited to 1.1
+#[must_use]
+pub fn k_z_fi(beta_m_z: f64, lambda_z_20: f64, lambda_z_theta: f64, n_ed: f64, n_b_z: f64) -> f64 {
+    let mu = 2.0f64
+        .mul_add(beta_m_z, -5.0)
+        .mul_add(
+            lambda_limited(lambda_z_20, lambda_z_theta),
+            0.44f64.mul_add(beta_m_z, 0.29),
+        )
+        .min(0.8);
+    (1.0 - mu * n_ed / n_b_z).min(3.0)
+}
+
+/// Interaction factor `k_LT` for lateral torsional buckling, 4.2.3.5(2)
+#[must_use]
+pub fn k_lt_fi(beta_m_lt: f64, lambda_z_theta: f64, n_ed: f64, n_b_z: f64) -> f64 {
+    let mu = (0.15 * lambda_z_theta).mul_add(beta_m_lt, -0.15).min(0.9);
+    (1.0 - mu * n_ed / n_b_z).min(1.0)
+}
+
+/// Critical temperature in degrees Celsius for the degree of utilisation `mu0` at time
+/// t = 0, 4.22, valid for `mu0` of at least 0.013
+#[must_use]
+pub fn f_4_22(mu0: f64) -> f64 {
+    39.19f64.mul_add((1.0 / (0.9674 * mu0.powf(3.833)) - 1.0).ln(), 482.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn reduction_factors_are_interpolated() {
+        assert_zeq!(k_y_theta(20.0), 1.0);
+        assert_zeq!(k_y_theta(550.0), 0.625);
+        assert_zeq!(k_p_theta(500.0), 0.36);
+        assert_zeq!(k_E_theta(250.0), 0.85);
+        assert_zeq!(k_y_theta(1300.0), 0.0);
+    }
+
+    #[test]
+    fn specific_heat_peaks_at_735_degrees() {
+        assert_zeq!(specific_heat(20.0).round(), 440.0);
+        assert_zeq!(specific_heat(735.0).round(), 5000.0);
+        assert_zeq!(specific_heat(1000.0), 650.0);
+    }
+
+    #[test]
+    fn unprotected_heating() {
+        // Am/V = 200 1/m at a heat flux of 50 kW/m^2 during 5 s
+        assert_zeq!(f_4_25(1.0, 200.0, 600.0, 7850.0, 50_000.0, 5.0), 10.615_711);
+    }
+
+    #[test]
+    fn protection_delays_heating() {
+        let phi = f_4_28(1200.0, 300.0, 0.02, 200.0, 600.0, 7850.0);
+        let d_theta = f_4_27(0.1, 200.0, 0.02, 600.0, 7850.0, phi, 800.0, 20.0, 1.0, 30.0);
+        assert_zeq!(d_theta, 4.477_625);
+    }
+
+    #[test]
+    fn stress_strain_curve_is_continuous() {
+        // S355 at 600 degrees
+        let (f_y, f_p, e_a) = (0.47 * 355.0, 0.18 * 355.0, 0.31 * 210_000.0);
+        let eps_p = f_p / e_a;
+        assert_zeq!(stress(eps_p, f_y, f_p, e_a), f_p);
+        assert_zeq!(stress(eps_p * 1.000_001, f_y, f_p, e_a), f_p);
+        assert_zeq!(stress(EPS_Y, f_y, f_p, e_a), f_y);
+        assert_zeq!(stress(0.175, f_y, f_p, e_a), f_y / 2.0);
+        assert_zeq!(stress(0.25, f_y, f_p, e_a), 0.0);
+    }
+
+    #[test]
+    fn fire_buckling_curve() {
+        assert_zeq!(alpha(235.0), 0.65);
+        assert_zeq!(f_4_7(1.0, 20.0), 1.0);
+        assert_zeq!(f_4_7(1.0, 600.0), 1.231_312);
+        assert_zeq!(f_4_6(0.0, 0.65), 1.0);
+        assert_zeq!(f_4_6(1.0, 0.65), 0.455_733);
+    }
+
+    #[test]
+    fn interaction_factors_are_bounded() {
+        assert_zeq!(beta_m(1.0), 1.1);
+        assert_zeq!(k_y_fi(1.1, 0.5, 0.5, 0.0, 1.0), 1.0);
+        assert_zeq!(k_y_fi(2.5, 2.0, 2.0, 1.0, 1.0), 0.2);
+        // Slenderness above 1.1 at 20 degrees does not increase the factors further
+        assert_zeq!(k_y_fi(1.1, 2.0, 2.0, 0.5, 1.0), 1.827);
+        assert_zeq!(k_z_fi(1.1, 2.0, 2.0, 0.5, 1.0), 2.153);
+        let (slender, limit) = (f_4_7(2.0, 600.0), f_4_7(1.1, 600.0));
+        assert_zeq!(
+            k_y_fi(1.1, 2.0, slender, 0.5, 1.0),
+            k_y_fi(1.1, 1.1, limit, 0.5, 1.0)
+        );
+        // The limit applies at 20 degrees, so heated slenderness may exceed 1.1
+        assert_zeq!(k_y_fi(1.1, 1.0, f_4_7(1.0, 600.0), 0.5, 1.0), 1.937_302);
+        assert_zeq!(k_lt_fi(1.1, 0.5, 1.0, 1.0), 1.0);
+        assert_zeq!(k_lt_fi(2.5, 2.0, 0.5, 1.0), 0.7);
+    }
+
+    #[test]
+    fn critical_temperature() {
+        // Table 4.1
+        assert_zeq!(f_4_22(0.5).round(), 585.0);
+        assert_zeq!(f_4_22(0.22).round(), 711.0);
+    }
+}
@@ -0,0 +1,88 @@
+use super::NSEN_1993::CompressionPart;
+
+/// Buckling reduction factor phi with a plateau of length `lambda_0`, 5.4.2(1)
+#[must_use]
+pub fn f_5_3(alpha: f64, lambda: f64, lambda_0: f64) -> f64 {
+    0.5 * lambda.mul_add(lambda, alpha.mul_add(lambda - lambda_0, 1.0))
+}
+
+/// Material factor epsilon for stainless steel, Table 5.2
+#[must_use]
+pub fn epsilon(fy: f64, youngs_modulus: f64) -> f64 {
+    (235.0 / fy * youngs_modulus / 210_000.0).sqrt()
+}
+
+/// Maximum width-to-thickness ratios of Class 1, 2 and 3 parts, Table 5.2
+///
+/// Plate limits are on c/t and scale with epsilon, tube limits are on d/t and scale with
+/// epsilon squared
+#[must_use]
+pub fn class_limits(part: &CompressionPart, welded: bool, epsilon: f64) -> [f64; 3] {
+    let (limits, scale) = match part {
+        CompressionPart::InternalBending => ([56.0, 58.2, 74.8], epsilon),
+        CompressionPart::InternalCompression => ([25.7, 26.7, 30.7], epsilon),
+        CompressionPart::OutstandCompression if welded => ([9.0, 9.4, 11.0], epsilon),
+        CompressionPart::OutstandCompression => ([10.0, 10.4, 11.9], epsilon),
+        CompressionPart::TubeBending => ([50.0, 70.0, 280.0], epsilon.powi(2)),
+        CompressionPart::TubeCompression => ([50.0, 70.0, 90.0], epsilon.powi(2)),
+    };
+    limits.map(|limit| limit * scale)
+}
+
+/// Imperfection factor and plateau length for flexural buckling, Table 5.3
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BucklingForm {
+    ColdFormedOpen,
+    Hollow,
+    WeldedOpenMajor,
+    WeldedOpenMinor,
+    /// Torsional and torsional-flexural buckling
+    Torsional,
+    /// Lateral torsional buckling of cold formed sections
+    LateralColdFormed,
+    /// Lateral torsional buckling of welded sections
+    LateralWelded,
+}
+
+impl BucklingForm {
+    /// (alpha, `lambda_0`)
+    #[must_use]
+    pub const fn imperfection(&self) -> (f64, f64) {
+        match self {
+            Self::ColdFormedOpen | Self::Hollow => (0.49, 0.40),
+            Self::WeldedOpenMajor => (0.49, 0.20),
+            Self::WeldedOpenMinor => (0.76, 0.20),
+            Self::Torsional => (0.34, 0.20),
+            Self::LateralColdFormed => (0.34, 0.40),
+            Self::LateralWelded => (0.76, 0.40),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn phi_with_plateau() {
+        assert_zeq!(f_5_3(0.49, 1.0, 0.4), 1.147);
+        assert_zeq!(f_5_3(0.49, 1.0, 0.2), 1.196);
+    }
+
+    #[test]
+    fn stainless_limits_are_stricter_than_carbon_steel() {
+        let limits = class_limits(&CompressionPart::InternalCompression, false, 1.0);
+        assert_zeq!(limits[2], 30.7);
+        let limits = class_limits(&CompressionPart::OutstandCompression, true, 0.5);
+        assert_zeq!(limits[0], 4.5);
+        let limits = class_limits(&CompressionPart::TubeBending, false, 0.5);
+        assert_zeq!(limits[2], 70.0);
+    }
+
+    #[test]
+    fn epsilon_accounts_for_stiffness() {
+        assert_zeq!(epsilon(235.0, 210_000.0), 1.0);
+        assert_zeq!(epsilon(230.0, 200_000.0), 0.986_451);
+    }
+}
@@ -0,0 +1,561 @@
+use std::f64::consts::PI;
+
+use super::NSEN_1993::f_6_49;
+
+/*----------------- PLATE BUCKLING, SECTION 4 ------------------*/
+
+/// Reduction factor for internal compression elements with the stress ratio `psi`, 4.2
+#[must_use]
+pub fn f_4_2(lambda_p: f64, psi: f64) -> f64 {
+    if lambda_p <= 0.5 + 0.055f64.mul_add(-psi, 0.085).sqrt() {
+        return 1.0;
+    }
+    (0.055f64.mul_add(-(3.0 + psi), lambda_p) / lambda_p.powi(2)).min(1.0)
+}
+
+/// Reduction factor for outstand compression elements, 4.3
+#[must_use]
+pub fn f_4_3(lambda_p: f64) -> f64 {
+    if lambda_p <= 0.748 {
+        return 1.0;
+    }
+    ((lambda_p - 0.188) / lambda_p.powi(2)).min(1.0)
+}
+
+/// Plate slenderness of an element of width `b` and thickness `t`, 4.4
+#[must_use]
+pub fn f_4_4(b: f64, t: f64, eps: f64, k_sigma: f64) -> f64 {
+    b / t / (28.4 * eps * k_sigma.sqrt())
+}
+
+/// Buckling factor of internal compression elements with the stress ratio `psi`, Table 4.1
+#[must_use]
+pub fn k_sigma(psi: f64) -> f64 {
+    if psi >= 1.0 {
+        4.0
+    } else if psi > 0.0 {
+        8.2 / (1.05 + psi)
+    } else if psi >= -1.0 {
+        (9.78 * psi).mul_add(psi, 6.29f64.mul_add(-psi, 7.81))
+    } else {
+        5.98 * (1.0 - psi).powi(2)
+    }
+}
+
+/// Effective area of the compression zone of a stiffened plate, 4.5, where `area_edge` is the
+/// effective area supported by the adjacent plate elements
+#[must_use]
+pub const fn f_4_5(rho_c: f64, area_eff_loc: f64, area_edge: f64) -> f64 {
+    rho_c.mul_add(area_eff_loc, area_edge)
+}
+
+/// Relative slenderness of plate-like and column-like buckling, 4.7 and 4.11, where `beta_a_c`
+/// is the ratio of the effective to the gross area
+#[must_use]
+pub fn f_4_7(beta_a_c: f64, fy: f64, sigma_cr: f64) -> f64 {
+    (beta_a_c * fy / sigma_cr).sqrt()
+}
+
+/// Elastic critical column buckling stress of a stiffener with the adjacent plating, of second
+/// moment of area `i_sl` and area `a_sl`, between transverse stiffeners at `a`, 4.9
+#[must_use]
+pub fn f_4_9(e: f64, i_sl: f64, a_sl: f64, a: f64) -> f64 {
+    PI.powi(2) * e * i_sl / (a_sl * a.powi(2))
+}
+
+/// Imperfection factor of column-like buckling, 4.12
+///
+/// `alpha` is 0.34 for closed and 0.49 for open stiffeners, `i` the radius of gyration of the
+/// stiffener and `e` the larger distance from its centroid to the plate or to the centroid of
+/// the stiffener alone
+#[must_use]
+pub fn f_4_12(alpha: f64, i: f64, e: f64) -> f64 {
+    alpha + 0.09 / (i / e)
+}
+
+/// Reduction factor for column-like buckling, 4.5.3(5), from the buckling curve of
+/// NS-EN 1993-1-1 6.3.1.2 with the imperfection factor `alpha_e`
+#[must_use]
+pub fn khi_c(alpha_e: f64, lambda_c: f64) -> f64 {
+    let phi = 0.5 * lambda_c.mul_add(lambda_c, alpha_e.mul_add(lambda_c - 0.2, 1.0));
+    f_6_49(phi, lambda_c)
+}
+
+/// Interpolation between plate-like and column-like buckling, 4.13
+#[must_use]
+pub fn f_4_13(rho: f64, khi_c: f64, xi: f64) -> f64 {
+    ((rho - khi_c) * xi).mul_add(2.0 - xi, khi_c)
+}
+
+/// Weighting of plate-like buckling in 4.13, 4.14
+#[must_use]
+pub fn f_4_14(sigma_cr_p: f64, sigma_cr_c: f64) -> f64 {
+    (sigma_cr_p / sigma_cr_c - 1.0).clamp(0.0, 1.0)
+}
+
+/*----------------- SHEAR BUCKLING, SECTION 5 ------------------*/
+
+/// Stiffness of the end post of a web panel at a support, 5.1(2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EndPost {
+    /// End post designed to anchor the tension field, 9.3.1
+    Rigid,
+    NonRigid,
+}
+
+/// Shear buckling resistance of the web and flanges, limited by the plastic shear resistance of
+/// the web, 5.1
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn f_5_1(v_bw: f64, v_bf: f64, eta: f64, f_yw: f64, h_w: f64, t: f64, g1: f64) -> f64 {
+    (v_bw + v_bf).min(eta * f_yw * h_w * t / (3f64.sqrt() * g1))
+}
+
+/// Contribution from the web, 5.2
+#[must_use]
+pub fn f_5_2(khi_w: f64, f_yw: f64, h_w: f64, t: f64, g1: f64) -> f64 {
+    khi_w * f_yw * h_w * t / (3f64.sqrt() * g1)
+}
+
+/// Slenderness of the web panel, 5.3
+#[must_use]
+pub fn f_5_3(f_yw: f64, tau_cr: f64) -> f64 {
+    0.76 * (f_yw / tau_cr).sqrt()
+}
+
+/// Elastic critical shear stress, 5.4
+#[must_use]
+pub fn f_5_4(k_tau: f64, sigma_e: f64) -> f64 {
+    k_tau * sigma_e
+}
+
+/// Euler stress of a plate of thickness `t` and width `b` in N/mm^2, A.1
+#[must_use]
+pub fn sigma_e(t: f64, b: f64) -> f64 {
+    190_000.0 * (t / b).powi(2)
+}
+
+/// Shear buckling coefficient of a panel of length `a` between rigid transverse stiffeners, A.5,
+/// where `k_tau_sl` is the contribution of longitudinal stiffeners
+#[must_use]
+pub fn k_tau(a: f64, h_w: f64, k_tau_sl: f64) -> f64 {
+    let ratio = (h_w / a).powi(2);
+    if a >= h_w {
+        4.0f64.mul_add(ratio, 5.34 + k_tau_sl)
+    } else {
+        5.34f64.mul_add(ratio, 4.0 + k_tau_sl)
+    }
+}
+
+/// Contribution from the web to shear buckling, Table 5.1
+#[must_use]
+pub fn khi_w(lambda_w: f64, eta: f64, end_post: EndPost) -> f64 {
+    if lambda_w < 0.83 / eta {
+        eta
+    } else if lambda_w < 1.08 || end_post == EndPost::NonRigid {
+        0.83 / lambda_w
+    } else {
+        1.37 / (0.7 + lambda_w)
+    }
+}
+
+/// Distance `c` from the flange to the plastic hinges of the tension field, 5.4(1)
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn c(a: f64, b_f: f64, t_f: f64, f_yf: f64, t: f64, h_w: f64, f_yw: f64) -> f64 {
+    a * (1.6 * b_f * t_f.powi(2) * f_yf / (t * h_w.powi(2) * f_yw) + 0.25)
+}
+
+/// Contribution from the flanges, 5.8, where `m_f_rd` is the moment resistance of the flanges
+/// alone. No contribution once the flanges are fully used by `m_ed`
+#[must_use]
+pub fn f_5_8(b_f: f64, t_f: f64, f_yf: f64, c: f64, g1: f64, m_ed: f64, m_f_rd: f64) -> f64 {
+    let ratio = m_ed / m_f_rd;
+    b_f * t_f.powi(2) * f_yf / (c * g1) * ratio.mul_add(-ratio, 1.0).max(0.0)
+}
+
+/*----------------- TRANSVERSE FORCES, SECTION 6 ------------------*/
+
+/// Way a transverse force is applied to the web, Figure 6.1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransverseLoad {
+    /// Through one flange and resisted by shear forces in the web
+    A,
+    /// Through one flange and transferred through the web directly to the other flange
+    B,
+    /// Through one flange adjacent to an unstiffened end at the distance `c`
+    C { c: f64 },
+}
+
+/// Design resistance to transverse forces, 6.1
+#[must_use]
+pub fn f_6_1(f_yw: f64, l_eff: f64, t_w: f64, g1: f64) -> f64 {
+    f_yw * l_eff * t_w / g1
+}
+
+/// Reduction factor for local buckling under transverse forces, 6.3
+#[must_use]
+pub fn f_6_3(lambda_f: f64) -> f64 {
+    (0.5 / lambda_f).min(1.0)
+}
+
+/// Slenderness for transverse forces, 6.4
+#[must_use]
+pub fn f_6_4(l_y: f64, t_w: f64, f_yw: f64, f_cr: f64) -> f64 {
+    (l_y * t_w * f_yw / f_cr).sqrt()
+}
+
+/// Elastic critical force, 6.5
+#[must_use]
+pub fn f_6_5(k_f: f64, e: f64, t_w: f64, h_w: f64) -> f64 {
+    0.9 * k_f * e * t_w.powi(3) / h_w
+}
+
+/// Buckling coefficient of the web for the stiff bearing length `s_s` and the spacing `a` of
+/// transverse stiffeners, Figure 6.1
+#[must_use]
+pub fn k_f(load: &TransverseLoad, h_w: f64, a: f64, s_s: f64) -> f64 {
+    match load {
+        TransverseLoad::A => 2.0f64.mul_add((h_w / a).powi(2), 6.0),
+        TransverseLoad::B => 2.0f64.mul_add((h_w / a).powi(2), 3.5),
+        TransverseLoad::C { c } => (6.0 * (s_s + c)).mul_add(1.0 / h_w, 2.0).min(6.0),
+    }
+}
+
+/// Ratio of the flange to the web strength, 6.8
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn f_6_8(f_yf: f64, b_f: f64, f_yw: f64, t_w: f64) -> f64 {
+    f_yf * b_f / (f_yw * t_w)
+}
+
+/// Contribution of the web depth to the effective loaded length, 6.9, zero for stocky webs
+#[must_use]
+pub fn f_6_9(h_w: f64, t_f: f64, lambda_f: f64) -> f64 {
+    if lambda_f > 0.5 {
+        0.02 * (h_w / t_f).powi(2)
+    } else {
+        0.0
+    }
+}
+
+/// Effective loaded length for load types a and b, 6.10, limited to the stiffener spacing `a`
+#[must_use]
+pub fn f_6_10(s_s: f64, t_f: f64, m_1: f64, m_2: f64, a: f64) -> f64 {
+    (2.0 * t_f).mul_add(1.0 + (m_1 + m_2).sqrt(), s_s).min(a)
+}
+
+/// Length `l_e` for load type c, 6.11, limited to `s_s + c`
+#[must_use]
+pub fn f_6_11(k_f: f64, e: f64, t_w: f64, f_yw: f64, h_w: f64, s_s: f64, c: f64) -> f64 {
+    (k_f * e * t_w.powi(2) / (2.0 * f_yw * h_w)).min(s_s + c)
+}
+
+/// Effective loaded length for load type c, the lesser of 6.12 and 6.13
+#[must_use]
+pub fn f_6_12(l_e: f64, t_f: f64, m_1: f64, m_2: f64) -> f64 {
+    let ratio = l_e / t_f;
+    let f_6_12 = t_f.mul_add(ratio.mul_add(ratio, m_1 / 2.0 + m_2).sqrt(), l_e);
+    let f_6_13 = t_f.mul_add((m_1 + m_2).sqrt(), l_e);
+    f_6_12.min(f_6_13)
+}
+
+/*----------------- INTERACTION, SECTION 7 ------------------*/
+
+/// Interaction between shear force and bending moment, 7.1
+///
+/// `eta_1` is the bending moment over the plastic moment resistance and `eta_3` the shear force
+/// over the contribution from the web. Only bending is checked for `eta_3` up to 0.5 or moments
+/// the flanges carry alone
+#[must_use]
+pub fn f_7_1(eta_1: f64, eta_3: f64, m_f_rd: f64, m_pl_rd: f64) -> f64 {
+    if eta_3 <= 0.5 || eta_1 <= m_f_rd / m_pl_rd {
+        return eta_1;
+    }
+    (1.0 - m_f_rd / m_pl_rd).mul_add(2.0f64.mul_add(eta_3, -1.0).powi(2), eta_1)
+}
+
+/// Interaction between transverse force, bending moment and axial force, 7.2, as a utilisation
+/// of the limit 1.4
+#[must_use]
+pub fn f_7_2(eta_1: f64, eta_2: f64) -> f64 {
+    0.8f64.mul_add(eta_1, eta_2) / 1.4
+}
+
+/*----------------- STIFFENED PLATES, ANNEX A ------------------*/
+
+/// Buckling factor of a plate with at least three equally spaced longitudinal stiffeners, A.2
+///
+/// `alpha = a / b` is the aspect ratio, `gamma` and `delta` the ratios of the second moment of
+/// area and of the area of the stiffeners to the plate, and `psi` the stress ratio
+#[must_use]
+pub fn f_a_2(alpha: f64, gamma: f64, delta: f64, psi: f64) -> f64 {
+    let alpha = alpha.max(0.5);
+    if alpha <= gamma.powf(0.25) {
+        let squared = alpha.mul_add(alpha, 1.0);
+        2.0 * (squared.mul_add(squared, gamma) - 1.0)
+            / (alpha.powi(2) * (psi + 1.0) * (1.0 + delta))
+    } else {
+        4.0 * (1.0 + gamma.sqrt()) / ((psi + 1.0) * (1.0 + delta))
+    }
+}
+
+/// Elastic critical stress of a single stiffener in the compression zone, A.4, at `b_1` and
+/// `b_2` from the edges of a plate of width `b`, with the limit length `a_c` of A.5
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn f_a_4(e: f64, i_sl: f64, a_sl: f64, t: f64, b: f64, b_1: f64, b_2: f64, a: f64) -> f64 {
+    let (t3b, b1b2) = (t.powi(3) * b, (b_1 * b_2).powi(2));
+    let a_c = 4.33 * (i_sl * b1b2 / t3b).powf(0.25);
+    if a >= a_c {
+        1.05 * e / a_sl * (i_sl * t3b).sqrt() / (b_1 * b_2)
+    } else {
+        f_4_9(e, i_sl, a_sl, a) + e * t3b * a.powi(2) / (4.0 * PI.powi(2) * 0.91 * a_sl * b1b2)
+    }
+}
+
+/// Plate of width `b` between webs, stiffened by `n` equally spaced identical longitudinal
+/// stiffeners and uniformly compressed between transverse stiffeners at `a`, 4.5
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StiffenedPlate {
+    pub b: f64,
+    pub t: f64,
+    pub a: f64,
+    pub n: u32,
+    /// Area of one stiffener without the plate
+    pub area_sl: f64,
+    /// Second moment of area of one stiffener with the plating between two stiffeners, about
+    /// its own centroid
+    pub i_sl: f64,
+    /// Larger distance from the centroid of the stiffener with plating to the mid-plane of the
+    /// plate or to the centroid of the stiffener alone
+    pub e: f64,
+    /// Closed stiffeners, such as trough stiffeners, buckle on a better curve
+    pub closed: bool,
+}
+
+impl StiffenedPlate {
+    /// Width of the subpanels between stiffeners
+    #[must_use]
+    pub fn b_sub(&self) -> f64 {
+        self.b / f64::from(self.n + 1)
+    }
+    /// Reduction factor of the subpanels, 4.4(2)
+    #[must_use]
+    pub fn rho_loc(&self, fy: f64) -> f64 {
+        let lambda_p = f_4_4(self.b_sub(), self.t, (235.0 / fy).sqrt(), k_sigma(1.0));
+        f_4_2(lambda_p, 1.0)
+    }
+    /// Gross area of one stiffener with its plating
+    fn area_sl_1(&self) -> f64 {
+        self.b_sub().mul_add(self.t, self.area_sl)
+    }
+    /// Elastic critical plate buckling stress, A.2.1 for one stiffener, A.2.2 for two and A.1
+    /// for three or more
+    #[must_use]
+    pub fn sigma_cr_p(&self, youngs_modulus: f64) -> f64 {
+        let single = |i_sl, area_sl, b, b_1| {
+            f_a_4(
+                youngs_modulus,
+                i_sl,
+                area_sl,
+                self.t,
+                b,
+                b_1,
+                b - b_1,
+                self.a,
+            )
+        };
+        match self.n {
+            1 => single(self.i_sl, self.area_sl_1(), self.b, self.b / 2.0),
+            // Each stiffener buckling with the other as a rigid support, and both lumped into
+            // one at their resultant
+            2 => {
+                let (b_sub, area_sl_1) = (self.b_sub(), self.area_sl_1());
+                let one = single(self.i_sl, area_sl_1, 2.0 * b_sub, b_sub);
+                let lumped = single(2.0 * self.i_sl, 2.0 * area_sl_1, self.b, self.b / 2.0);
+                one.min(lumped)
+            }
+            _ => {
+                let n = f64::from(self.n);
+                let gamma = n * self.i_sl / (self.b * self.t.powi(3) / 10.92);
+                let delta = n * self.area_sl / (self.b * self.t);
+                f_a_2(self.a / self.b, gamma, delta, 1.0) * sigma_e(self.t, self.b)
+            }
+        }
+    }
+    /// Elastic critical column buckling stress, 4.9
+    #[must_use]
+    pub fn sigma_cr_c(&self, youngs_modulus: f64) -> f64 {
+        f_4_9(youngs_modulus, self.i_sl, self.area_sl_1(), self.a)
+    }
+    /// Gross and effective areas of the compression zone, without the edges supported by the
+    /// webs
+    fn areas_c(&self, fy: f64) -> (f64, f64) {
+        let (n, b_c) = (f64::from(self.n), self.b - self.b_sub());
+        let area_c = n.mul_add(self.area_sl, b_c * self.t);
+        let area_c_eff_loc = n.mul_add(self.area_sl, self.rho_loc(fy) * b_c * self.t);
+        (area_c, area_c_eff_loc)
+    }
+    /// Reduction factor for plate-like buckling, 4.5.2
+    #[must_use]
+    pub fn rho(&self, fy: f64, youngs_modulus: f64) -> f64 {
+        let (area_c, area_c_eff_loc) = self.areas_c(fy);
+        let lambda_p = f_4_7(area_c_eff_loc / area_c, fy, self.sigma_cr_p(youngs_modulus));
+        f_4_2(lambda_p, 1.0)
+    }
+    /// Reduction factor for column-like buckling, 4.5.3
+    #[must_use]
+    pub fn khi_c(&self, fy: f64, youngs_modulus: f64) -> f64 {
+        let area_sl_1 = self.area_sl_1();
+        let area_sl_1_eff = (self.rho_loc(fy) * self.b_sub()).mul_add(self.t, self.area_sl);
+        let lambda_c = f_4_7(
+            area_sl_1_eff / area_sl_1,
+            fy,
+            self.sigma_cr_c(youngs_modulus),
+        );
+        let alpha = if self.closed { 0.34 } else { 0.49 };
+        let i = (self.i_sl / area_sl_1).sqrt();
+        khi_c(f_4_12(alpha, i, self.e), lambda_c)
+    }
+    /// Final reduction factor of the compression zone, 4.5.4
+    #[must_use]
+    pub fn rho_c(&self, fy: f64, youngs_modulus: f64) -> f64 {
+        let xi = f_4_14(
+            self.sigma_cr_p(youngs_modulus),
+            self.sigma_cr_c(youngs_modulus),
+        );
+        f_4_13(
+            self.rho(fy, youngs_modulus),
+            self.khi_c(fy, youngs_modulus),
+            xi,
+        )
+    }
+    /// Effective area of the stiffened plate in compression, 4.5
+    #[must_use]
+    pub fn area_eff(&self, fy: f64, youngs_modulus: f64) -> f64 {
+        let (_, area_c_eff_loc) = self.areas_c(fy);
+        let area_edge = self.rho_loc(fy) * self.b_sub() * self.t;
+        f_4_5(self.rho_c(fy, youngs_modulus), area_c_eff_loc, area_edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn unstiffened_plates() {
+        assert_zeq!(k_sigma(1.0), 4.0);
+        assert_zeq!(k_sigma(0.0), 7.81);
+        assert_zeq!(k_sigma(-1.0), 23.88);
+        assert_zeq!(k_sigma(-2.0), 53.82);
+        assert_zeq!(f_4_2(0.6, 1.0), 1.0);
+        assert_zeq!(f_4_2(1.0, 1.0), 0.78);
+        assert_zeq!(f_4_3(1.0), 0.812);
+        assert_zeq!(f_4_13(0.9, 0.6, 0.0), 0.6);
+        assert_zeq!(f_4_13(0.9, 0.6, 1.0), 0.9);
+        assert_zeq!(f_4_14(300.0, 100.0), 1.0);
+    }
+    #[test]
+    fn column_like_buckling_of_stiffened_plates() {
+        // Three flat stiffeners 200 x 20 on a 12 mm plate
+        let plate = StiffenedPlate {
+            b: 2000.0,
+            t: 12.0,
+            a: 4000.0,
+            n: 3,
+            area_sl: 4000.0,
+            i_sl: 40_371_733.0 + 1.0 / 3.0,
+            e: 63.6,
+            closed: false,
+        };
+        let (fy, e) = (355.0, 210_000.0);
+        assert_zeq!(plate.rho_loc(fy), 0.838_489);
+        assert_zeq!(plate.sigma_cr_p(e), 463.627_043);
+        assert_zeq!(plate.sigma_cr_c(e), 522.969_611);
+        assert_zeq!(plate.rho(fy, e), 0.884_403);
+        assert_zeq!(plate.khi_c(fy, e), 0.641_998);
+        // Column-like buckling governs as it is more critical than plate-like buckling
+        assert_zeq!(plate.rho_c(fy, e), plate.khi_c(fy, e));
+        assert_zeq!(plate.area_eff(fy, e), 22_424.448_947);
+    }
+    #[test]
+    fn two_stiffeners_are_checked_alone_and_lumped() {
+        // Two flat stiffeners 150 x 15 on a 10 mm plate
+        let plate = StiffenedPlate {
+            b: 1500.0,
+            t: 10.0,
+            a: 3000.0,
+            n: 2,
+            area_sl: 2250.0,
+            i_sl: 14_191_451.149_425_287,
+            e: 55.172_413_793_103_45,
+            closed: false,
+        };
+        let e = 210_000.0;
+        // The lumped stiffener governs over each stiffener supported by the other
+        assert_zeq!(plate.sigma_cr_p(e), 467.981_131);
+        assert!(
+            plate.sigma_cr_p(e) < f_a_4(e, plate.i_sl, 7250.0, 10.0, 1000.0, 500.0, 500.0, 3000.0)
+        );
+    }
+    #[test]
+    fn single_stiffener_interpolates_plate_and_column_buckling() {
+        let plate = StiffenedPlate {
+            b: 1000.0,
+            t: 10.0,
+            a: 3000.0,
+            n: 1,
+            area_sl: 2000.0,
+            i_sl: 6_029_761.904_761_905,
+            e: 39.285_714_285_714_285,
+            closed: false,
+        };
+        let (fy, e) = (355.0, 210_000.0);
+        assert_zeq!(plate.sigma_cr_p(e), 318.620_532);
+        assert_zeq!(plate.sigma_cr_c(e), 198.371_215);
+        assert_zeq!(plate.rho_c(fy, e), 0.745_452);
+        assert_zeq!(plate.area_eff(fy, e), 7_917.032_841);
+    }
+    #[test]
+    fn shear_buckling_coefficient() {
+        // Long panels tend to the 5.34 of webs stiffened at the supports only
+        assert_zeq!(k_tau(1.0e9, 1000.0, 0.0), 5.34);
+        assert_zeq!(k_tau(1000.0, 1000.0, 0.0), 9.34);
+        assert_zeq!(k_tau(500.0, 1000.0, 0.0), 25.36);
+        let tau_cr = f_5_4(5.34, sigma_e(10.0, 1000.0));
+        assert_zeq!(f_5_3(355.0, tau_cr), 1.421_610);
+    }
+    #[test]
+    fn web_contribution() {
+        assert_zeq!(khi_w(0.5, 1.2, EndPost::NonRigid), 1.2);
+        assert_zeq!(khi_w(1.0, 1.2, EndPost::Rigid), 0.83);
+        assert_zeq!(khi_w(2.0, 1.2, EndPost::NonRigid), 0.415);
+        assert_zeq!(khi_w(2.0, 1.2, EndPost::Rigid), 0.507_407);
+    }
+    #[test]
+    fn transverse_force_coefficients() {
+        assert_zeq!(k_f(&TransverseLoad::A, 1000.0, 1000.0, 100.0), 8.0);
+        assert_zeq!(k_f(&TransverseLoad::B, 1000.0, 2000.0, 100.0), 4.0);
+        assert_zeq!(
+            k_f(&TransverseLoad::C { c: 50.0 }, 1000.0, 2000.0, 100.0),
+            2.9
+        );
+        assert_zeq!(
+            k_f(&TransverseLoad::C { c: 900.0 }, 1000.0, 2000.0, 100.0),
+            6.0
+        );
+        assert_zeq!(f_6_3(0.4), 1.0);
+        assert_zeq!(f_6_9(1000.0, 20.0, 0.5), 0.0);
+        assert_zeq!(f_6_9(1000.0, 20.0, 0.6), 50.0);
+        assert_zeq!(f_7_2(0.5, 1.0), 1.0);
+    }
+    #[test]
+    fn bending_and_shear_interaction() {
+        assert_zeq!(f_7_1(0.8, 0.4, 0.6, 1.0), 0.8);
+        assert_zeq!(f_7_1(0.5, 0.9, 0.6, 1.0), 0.5);
+        assert_zeq!(f_7_1(0.8, 0.9, 0.6, 1.0), 1.056);
+    }
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+/// Service class of the structure, 2.3.1.3
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ServiceClass {
+    /// Heated indoor climate, moisture content rarely above 12%
+    #[default]
+    One,
+    /// Covered and unheated, moisture content rarely above 20%
+    Two,
+    /// Exposed to weather, higher moisture content
+    Three,
+}
+
+/// Load duration class of the governing action, Table 2.1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoadDuration {
+    /// More than 10 years, e.g. self weight
+    Permanent,
+    /// 6 months to 10 years, e.g. storage
+    LongTerm,
+    /// 1 week to 6 months, e.g. imposed floor load and snow
+    #[default]
+    MediumTerm,
+    /// Less than 1 week, e.g. wind
+    ShortTerm,
+    /// Accidental actions
+    Instantaneous,
+}
+
+/// Modification factor for solid timber and glued laminated timber, Table 3.1
+#[allow(clippy::match_same_arms)]
+#[must_use]
+pub const fn k_mod(service_class: &ServiceClass, load_duration: &LoadDuration) -> f64 {
+    match (service_class, load_duration) {
+        (ServiceClass::One | ServiceClass::Two, LoadDuration::Permanent) => 0.60,
+        (ServiceClass::One | ServiceClass::Two, LoadDuration::LongTerm) => 0.70,
+        (ServiceClass::One | ServiceClass::Two, LoadDuration::MediumTerm) => 0.80,
+        (ServiceClass::One | ServiceClass::Two, LoadDuration::ShortTerm) => 0.90,
+        (ServiceClass::One | ServiceClass::Two, LoadDuration::Instantaneous) => 1.10,
+        (ServiceClass::Three, LoadDuration::Permanent) => 0.50,
+        (ServiceClass::Three, LoadDuration::LongTerm) => 0.55,
+        (ServiceClass::Three, LoadDuration::MediumTerm) => 0.65,
+        (ServiceClass::Three, LoadDuration::ShortTerm) => 0.70,
+        (ServiceClass::Three, LoadDuration::Instantaneous) => 0.90,
+    }
+}
+
+/// Depth factor for solid timber with depth `h` below 150 mm, 3.2(3)
+#[must_use]
+pub fn f_3_1(h: f64) -> f64 {
+    (150.0 / h).powf(0.2).clamp(1.0, 1.3)
+}
+
+/// Depth factor for glued laminated timber with depth `h` below 600 mm, 3.3(3)
+#[must_use]
+pub fn f_3_2(h: f64) -> f64 {
+    (600.0 / h).powf(0.1).clamp(1.0, 1.1)
+}
+
+/*---------------- CROSS-SECTION ----------------*/
+
+/// Utilisation in bending, 6.1.6(1), where `k_m` is 0.7 for rectangular sections
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn f_6_11(sigma_my: f64, sigma_mz: f64, f_my: f64, f_mz: f64, k_m: f64) -> f64 {
+    k_m.mul_add(sigma_mz / f_mz, sigma_my / f_my)
+}
+
+/// Utilisation in bending, 6.1.6(1), with the reduction applied to the y-axis
+#[allow(clippy::similar_names)]
+#[must_use]
+pub fn f_6_12(sigma_my: f64, sigma_mz: f64, f_my: f64, f_mz: f64, k_m: f64) -> f64 {
+    k_m.mul_add(sigma_my / f_my, sigma_mz / f_mz)
+}
+
+/// Maximum shear stress of a rectangular section, 6.1.7, with the effective width `b_ef`
+#[must_use]
+pub fn f_6_13(v: f64, b_ef: f64, h: f64) -> f64 {
+    1.5 * v / (b_ef * h)
+}
+
+/// Utilisation in combined bending and axial tension, 6.2.3(1), where `bending` is the
+/// utilisation from 6.11 or 6.12
+#[must_use]
+pub fn f_6_17(sigma_t: f64, f_t: f64, bending: f64) -> f64 {
+    sigma_t / f_t + bending
+}
+
+/// Utilisation in combined bending and axial compression, 6.2.4(1), where `bending` is the
+/// utilisation from 6.11 or 6.12
+#[must_use]
+pub fn f_6_19(sigma_c: f64, f_c: f64, bending: f64) -> f64 {
+    (sigma_c / f_c).mul_add(sigma_c / f_c, bending)
+}
+
+/*----------------- BUCKLING ------------------*/
+
+/// Relative slenderness, 6.3.2(1), for the slenderness ratio `lambda` = `l_k` / i
+#[must_use]
+pub fn f_6_21(lambda: f64, f_c_0_k: f64, e_0_05: f64) -> f64 {
+    lambda / std::f64::consts::PI * (f_c_0_k / e_0_05).sqrt()
+}
+
+/// Utilisation of a column in compression and bending, 6.3.2(3), where `k_c` is the
+/// instability factor about the buckling axis and `bending` the utilisation from 6.11 or 6.12
+#[must_use]
+pub fn f_6_23(sigma_c: f64, k_c: f64, f_c: f64, bending: f64) -> f64 {
+    sigma_c / (k_c * f_c) + bending
+}
+
+/// Instability factor, 6.3.2(3), where `beta_c` is 0.2 for solid timber and 0.1 for glulam
+#[must_use]
+pub fn f_6_25(lambda_rel: f64, beta_c: f64) -> f64 {
+    if lambda_rel <= 0.3 {
+        return 1.0;
+    }
+    let k = f_6_27(lambda_rel, beta_c);
+    (1.0 / (k + k.mul_add(k, -lambda_rel.powi(2)).sqrt())).min(1.0)
+}
+
+/// Factor k for the instability factor, 6.3.2(3)
+#[must_use]
+pub fn f_6_27(lambda_rel: f64, beta_c: f64) -> f64 {
+    0.5 * lambda_rel.mul_add(lambda_rel, beta_c.mul_add(lambda_rel - 0.3, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn k_mod_follows_table_3_1() {
+        assert_zeq!(k_mod(&ServiceClass::One, &LoadDuration::MediumTerm), 0.8);
+        assert_zeq!(k_mod(&ServiceClass::Three, &LoadDuration::ShortTerm), 0.7);
+    }
+
+    #[test]
+    fn depth_factor_is_capped() {
+        assert_zeq!(f_3_1(200.0), 1.0);
+        assert_zeq!(f_3_1(10.0), 1.3);
+        assert_zeq!(f_3_2(300.0), 1.071_773);
+    }
+
+    #[test]
+    fn instability_factor() {
+        assert_zeq!(f_6_25(0.3, 0.2), 1.0);
+        assert_zeq!(f_6_25(1.0, 0.2), 0.689_343);
+        assert!(f_6_25(1.0, 0.1) > f_6_25(1.0, 0.2));
+    }
+}
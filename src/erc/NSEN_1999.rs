@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+/// Yielding of the gross cross-section in tension, 6.2.3
+#[must_use]
+pub fn N_o_Rd(area: f64, fo: f64, gamma_m1: f64) -> f64 {
+    area * fo / gamma_m1
+}
+
+/// Ultimate resistance of the net section in tension, 6.2.3
+#[must_use]
+pub fn N_u_Rd(area_net: f64, fu: f64, gamma_m2: f64) -> f64 {
+    0.9 * area_net * fu / gamma_m2
+}
+
+/// Yielding resistance in bending, 6.2.5, where `alpha` is the shape factor of Table 6.4
+#[must_use]
+pub fn M_o_Rd(alpha: f64, w_el: f64, fo: f64, gamma_m1: f64) -> f64 {
+    alpha * w_el * fo / gamma_m1
+}
+
+/// Ultimate resistance of the net section in bending, 6.2.5
+#[must_use]
+pub fn M_u_Rd(w_net: f64, fu: f64, gamma_m2: f64) -> f64 {
+    w_net * fu / gamma_m2
+}
+
+/*----------------- BUCKLING ------------------*/
+
+/// Flexural buckling resistance, 6.3.1.1
+#[must_use]
+pub fn N_b_Rd(kappa: f64, khi: f64, area_eff: f64, fo: f64, gamma_m1: f64) -> f64 {
+    kappa * khi * area_eff * fo / gamma_m1
+}
+
+/// Buckling reduction factor, 6.3.1.2
+#[must_use]
+pub fn khi(class: &BucklingClass, lambda: f64) -> f64 {
+    let (alpha, lambda_0) = class.imperfection();
+    let phi = 0.5 * lambda.mul_add(lambda, alpha.mul_add(lambda - lambda_0, 1.0));
+    (1.0 / (phi + phi.mul_add(phi, -lambda.powi(2)).sqrt())).clamp(0.0, 1.0)
+}
+
+/// Factor allowing for the weakening effect of longitudinal welding, Table 6.5,
+/// where `haz_ratio` is the part of the section area inside the heat affected zone
+#[must_use]
+pub fn kappa(class: &BucklingClass, lambda: f64, haz_ratio: f64, rho_o_haz: f64) -> f64 {
+    if haz_ratio <= 0.0 {
+        return 1.0;
+    }
+    match class {
+        BucklingClass::A => {
+            // A1 / A with the heat affected zone reduced by rho_o,haz
+            let a1 = haz_ratio.mul_add(-(1.0 - rho_o_haz), 1.0);
+            0.1f64.mul_add(a1, 0.05).mul_add(
+                -lambda.powf(1.3 * (1.0 - lambda)),
+                (1.0 - a1).mul_add(-(10f64.powf(-lambda)), 1.0),
+            )
+        }
+        BucklingClass::B => {
+            if lambda <= 0.2 {
+                1.0
+            } else {
+                0.04f64.mul_add(
+                    (4.0 * lambda).powf(0.5 - lambda),
+                    0.22f64.mul_add(-lambda.powf(1.4 * (1.0 - lambda)), 1.0),
+                )
+            }
+        }
+    }
+    .min(1.0)
+}
+
+/// Buckling class of the alloy, Table 3.2
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BucklingClass {
+    A,
+    B,
+}
+
+impl BucklingClass {
+    /// (alpha, `lambda_0`) for flexural buckling, Table 6.6
+    #[must_use]
+    pub const fn imperfection(&self) -> (f64, f64) {
+        match self {
+            Self::A => (0.20, 0.10),
+            Self::B => (0.32, 0.00),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn tension_resistances() {
+        assert_zeq!(N_o_Rd(1000.0, 250.0, 1.1), 227_272.727_272);
+        assert_zeq!(N_u_Rd(1000.0, 290.0, 1.25), 208_800.0);
+    }
+
+    #[test]
+    fn bending_resistances() {
+        assert_zeq!(M_o_Rd(1.2, 1000.0, 250.0, 1.1), 272_727.272_727);
+        assert_zeq!(M_u_Rd(1000.0, 290.0, 1.25), 232_000.0);
+    }
+
+    #[test]
+    fn khi_has_plateau() {
+        assert_zeq!(khi(&BucklingClass::A, 0.1), 1.0);
+        assert_zeq!(khi(&BucklingClass::A, 1.0), 0.656_295);
+        assert_zeq!(khi(&BucklingClass::B, 1.0), 0.572_122);
+    }
+
+    #[test]
+    fn kappa_is_one_without_welds() {
+        assert_zeq!(kappa(&BucklingClass::A, 1.0, 0.0, 0.5), 1.0);
+        assert!(kappa(&BucklingClass::A, 0.5, 0.3, 0.5) < 1.0);
+        assert!(kappa(&BucklingClass::B, 0.5, 0.3, 0.5) < 1.0);
+    }
+}
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+use super::NSEN_1993::BuckleCurve;
+
+/// National Annex supplying the nationally determined parameters of EN 1993
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NationalAnnex {
+    /// Values recommended in the Eurocode itself
+    Recommended,
+    /// Norway, NS-EN 1993-1-1:2005+NA:2015
+    #[default]
+    NS,
+    /// Sweden, SS-EN 1993-1-1 as given by EKS
+    SS,
+    /// Denmark, DS/EN 1993-1-1 DK NA for normal inspection level
+    DS,
+}
+
+impl NationalAnnex {
+    #[must_use]
+    pub fn get(identifier: &str) -> Option<Self> {
+        match identifier {
+            "EN" | "Recommended" | "recommended" => Some(Self::Recommended),
+            "NS" | "NS-EN" | "Norway" => Some(Self::NS),
+            "SS" | "SS-EN" | "Sweden" => Some(Self::SS),
+            "DS" | "DS/EN" | "DS-EN" | "Denmark" => Some(Self::DS),
+            _ => None,
+        }
+    }
+    #[must_use]
+    pub fn variants() -> Vec<String> {
+        vec![
+            String::from("Recommended"),
+            String::from("NS"),
+            String::from("SS"),
+            String::from("DS"),
+        ]
+    }
+
+    /// Partial factor for resistance of cross-sections, 6.1(1)
+    #[must_use]
+    pub const fn gamma_m0(&self) -> f64 {
+        match self {
+            Self::Recommended | Self::SS => 1.00,
+            Self::NS => 1.05,
+            Self::DS => 1.10,
+        }
+    }
+    /// Partial factor for resistance of members to instability, 6.1(1)
+    #[must_use]
+    pub const fn gamma_m1(&self) -> f64 {
+        match self {
+            Self::Recommended | Self::SS => 1.00,
+            Self::NS => 1.05,
+            Self::DS => 1.20,
+        }
+    }
+    /// Partial factor for resistance of cross-sections in tension to fracture, 6.1(1)
+    #[must_use]
+    pub const fn gamma_m2(&self) -> f64 {
+        match self {
+            Self::Recommended | Self::NS => 1.25,
+            Self::SS => 1.20,
+            Self::DS => 1.35,
+        }
+    }
+    // The remaining parameters are the recommended values, which all the supported annexes
+    // adopt. They stay methods so that an annex choosing otherwise only changes its arm here.
+
+    /// Partial factor for the fire situation, NS-EN 1993-1-2 2.3(1)
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub const fn gamma_m_fi(&self) -> f64 {
+        1.00
+    }
+
+    /// Plateau length of the lateral torsional buckling curves for rolled or equivalent welded
+    /// sections, 6.3.2.3(1)
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub const fn lambda_lt0(&self) -> f64 {
+        0.4
+    }
+    /// Correction factor of the lateral torsional buckling curves for rolled or equivalent
+    /// welded sections, 6.3.2.3(1)
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub const fn beta_lt(&self) -> f64 {
+        0.75
+    }
+    /// Factor on the web area included in the shear area, NS-EN 1993-1-5 5.1(2)
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub fn eta(&self, fy: f64) -> f64 {
+        if fy > 460.0 {
+            1.0
+        } else {
+            1.2
+        }
+    }
+
+    /// Lateral torsional buckling curve for rolled or equivalent welded I-sections,
+    /// Table 6.5 for the 6.3.2.3 method
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub const fn ltb_curve(&self, welded: bool, h_over_b: f64) -> BuckleCurve {
+        match (welded, h_over_b > 2.0) {
+            (false, false) => BuckleCurve::B,
+            (false, true) | (true, false) => BuckleCurve::C,
+            (true, true) => BuckleCurve::D,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn partial_factors_differ_between_annexes() {
+        assert_zeq!(NationalAnnex::Recommended.gamma_m0(), 1.00);
+        assert_zeq!(NationalAnnex::NS.gamma_m1(), 1.05);
+        assert_zeq!(NationalAnnex::SS.gamma_m2(), 1.20);
+        assert_zeq!(NationalAnnex::DS.gamma_m1(), 1.20);
+    }
+
+    #[test]
+    fn default_annex_is_norwegian() {
+        assert_eq!(NationalAnnex::default(), NationalAnnex::NS);
+    }
+
+    #[test]
+    fn can_find_annex_by_name() {
+        for name in NationalAnnex::variants() {
+            assert!(NationalAnnex::get(&name).is_some());
+        }
+        assert_eq!(NationalAnnex::get("DS/EN"), Some(NationalAnnex::DS));
+    }
+
+    #[test]
+    fn other_parameters_are_recommended_values() {
+        for annex in [NationalAnnex::NS, NationalAnnex::SS, NationalAnnex::DS] {
+            let recommended = NationalAnnex::Recommended;
+            assert_zeq!(annex.gamma_m_fi(), recommended.gamma_m_fi());
+            assert_zeq!(annex.lambda_lt0(), recommended.lambda_lt0());
+            assert_zeq!(annex.beta_lt(), recommended.beta_lt());
+            assert_zeq!(annex.eta(355.0), recommended.eta(355.0));
+        }
+    }
+
+    #[test]
+    fn ltb_curve_follows_table_6_5() {
+        assert_zeq!(NationalAnnex::NS.ltb_curve(false, 1.0).alpha(), 0.34);
+        assert_zeq!(NationalAnnex::NS.ltb_curve(true, 3.0).alpha(), 0.76);
+    }
+}
@@ -1,2 +1,46 @@
+pub mod annex;
+#[allow(non_snake_case)]
+pub mod NSEN_1991_1_2;
 #[allow(non_snake_case)]
 pub mod NSEN_1993;
+#[allow(non_snake_case)]
+pub mod NSEN_1993_1_2;
+#[allow(non_snake_case)]
+pub mod NSEN_1993_1_4;
+#[allow(non_snake_case)]
+pub mod NSEN_1993_1_5;
+#[allow(non_snake_case)]
+pub mod NSEN_1995;
+#[allow(non_snake_case)]
+pub mod NSEN_1999;
+
+use serde::Serialize;
+
+/// Utilisation of a single design check, with the clause it was checked against
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Check {
+    pub clause: &'static str,
+    pub utilisation: f64,
+}
+
+impl Check {
+    #[must_use]
+    pub const fn new(clause: &'static str, utilisation: f64) -> Self {
+        Self {
+            clause,
+            utilisation,
+        }
+    }
+    #[must_use]
+    pub fn passes(&self) -> bool {
+        self.utilisation <= 1.0
+    }
+    /// The check with the highest utilisation
+    #[must_use]
+    pub fn governing(checks: &[Self]) -> Option<Self> {
+        checks
+            .iter()
+            .copied()
+            .max_by(|a, b| a.utilisation.total_cmp(&b.utilisation))
+    }
+}
@@ -18,6 +18,8 @@ pub enum EmsErrorKind {
     FileNotFound,
     FieldNotFound,
     DataBaseErr,
+    SectionNotFound,
+    OutOfRange,
 }
 
 impl fmt::Display for EmsError {
@@ -54,7 +56,19 @@ impl EmsError {
         Self::new(EmsErrorKind::FileNotFound, message, debug)
     }
     #[must_use]
+    pub fn field_not_found_error(message: String, debug: Option<Box<dyn Error>>) -> Self {
+        Self::new(EmsErrorKind::FieldNotFound, message, debug)
+    }
+    #[must_use]
     pub fn database_error(message: String, debug: Option<Box<dyn Error>>) -> Self {
         Self::new(EmsErrorKind::DataBaseErr, message, debug)
     }
+    #[must_use]
+    pub fn section_not_found_error(message: String, debug: Option<Box<dyn Error>>) -> Self {
+        Self::new(EmsErrorKind::SectionNotFound, message, debug)
+    }
+    #[must_use]
+    pub fn out_of_range_error(message: String, debug: Option<Box<dyn Error>>) -> Self {
+        Self::new(EmsErrorKind::OutOfRange, message, debug)
+    }
 }
@@ -7,6 +7,7 @@ pub mod err;
 pub mod load;
 pub mod mat;
 pub mod mmb;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Axis {
     X,
     Y,
@@ -27,6 +28,8 @@ impl Axis {
 pub enum LimitStateType {
     K,
     D,
+    /// Accidental design situation, such as fire, NS-EN 1990 6.4.3.3
+    A,
 }
 impl LimitStateType {
     #[must_use]
@@ -34,6 +37,7 @@ impl LimitStateType {
         match identifier {
             "K" | "k" | "Characteristic" | "characteristic" => Some(Self::K),
             "D" | "d" | "Design" | "design" => Some(Self::D),
+            "A" | "a" | "Accidental" | "accidental" => Some(Self::A),
             _ => None,
         }
     }
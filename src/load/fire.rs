@@ -0,0 +1,134 @@
+use crate::erc::NSEN_1991_1_2::{alpha_c, f_3_4, f_3_5, f_3_6, f_a_1, f_a_11, f_a_2a};
+use serde::{Deserialize, Serialize};
+
+/// Parametric temperature-time curve of a compartment fire, NS-EN 1991-1-2 Annex A
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ParametricFire {
+    /// Opening factor O in m^0.5
+    pub opening_factor: f64,
+    /// Thermal absorptivity b of the enclosure in J/m^2 s^0.5 K
+    pub absorptivity: f64,
+    /// Design fire load density related to the total enclosure surface in MJ/m^2
+    pub fire_load: f64,
+    /// Time for maximum gas temperature of a fuel controlled fire in hours, Table A.1
+    pub t_lim: f64,
+}
+
+impl ParametricFire {
+    #[must_use]
+    pub const fn new(opening_factor: f64, absorptivity: f64, fire_load: f64, t_lim: f64) -> Self {
+        Self {
+            opening_factor,
+            absorptivity,
+            fire_load,
+            t_lim,
+        }
+    }
+
+    /// Time of maximum temperature in hours, A.7
+    #[must_use]
+    pub fn t_max(&self) -> f64 {
+        (0.2e-3 * self.fire_load / self.opening_factor).max(self.t_lim)
+    }
+    #[must_use]
+    pub fn fuel_controlled(&self) -> bool {
+        0.2e-3 * self.fire_load / self.opening_factor < self.t_lim
+    }
+
+    /// Gas temperature at `t` minutes
+    #[must_use]
+    pub fn gas_temperature(&self, t: f64) -> f64 {
+        let hours = t / 60.0;
+        let gamma = f_a_2a(self.opening_factor, self.absorptivity);
+        let t_max = self.t_max();
+        let theta_max = if self.fuel_controlled() {
+            // A.9 and A.10 with the limiting opening factor
+            let o_lim = 0.1e-3 * self.fire_load / self.t_lim;
+            let gamma_lim = f_a_2a(o_lim, self.absorptivity);
+            if hours <= t_max {
+                return f_a_1(hours * gamma_lim);
+            }
+            f_a_1(self.t_lim * gamma_lim)
+        } else {
+            if hours <= t_max {
+                return f_a_1(hours * gamma);
+            }
+            f_a_1(t_max * gamma)
+        };
+        // A.12
+        let t_star_max = 0.2e-3 * self.fire_load / self.opening_factor * gamma;
+        let x = if self.fuel_controlled() {
+            self.t_lim * gamma / t_star_max
+        } else {
+            1.0
+        };
+        f_a_11(theta_max, hours * gamma, t_star_max, x)
+    }
+}
+
+/// Gas temperature-time curves of NS-EN 1991-1-2
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum FireCurve {
+    /// Standard temperature-time curve, 3.2.1
+    Iso834,
+    /// External fire curve, 3.2.2
+    External,
+    /// Hydrocarbon curve, 3.2.3
+    Hydrocarbon,
+    /// Compartment fire, Annex A
+    Parametric(ParametricFire),
+}
+
+impl FireCurve {
+    /// Gas temperature in degrees Celsius at `t` minutes
+    #[must_use]
+    pub fn gas_temperature(&self, t: f64) -> f64 {
+        match self {
+            Self::Iso834 => f_3_4(t),
+            Self::External => f_3_5(t),
+            Self::Hydrocarbon => f_3_6(t),
+            Self::Parametric(fire) => fire.gas_temperature(t),
+        }
+    }
+    /// Coefficient of heat transfer by convection in W/m^2 K
+    #[must_use]
+    pub const fn alpha_c(&self) -> f64 {
+        match self {
+            Self::Iso834 | Self::External => alpha_c::STANDARD,
+            Self::Hydrocarbon => alpha_c::HYDROCARBON,
+            Self::Parametric(_) => alpha_c::PARAMETRIC,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn hydrocarbon_is_hotter_than_standard() {
+        assert!(
+            FireCurve::Hydrocarbon.gas_temperature(10.0) > FireCurve::Iso834.gas_temperature(10.0)
+        );
+        assert_zeq!(FireCurve::Iso834.alpha_c(), 25.0);
+    }
+
+    #[test]
+    fn parametric_fire_heats_and_cools() {
+        // Ventilation controlled with Γ = 1, t_max = 0.2e-3 * 300 / 0.04 = 1.5 h
+        let curve = FireCurve::Parametric(ParametricFire::new(0.04, 1160.0, 300.0, 20.0 / 60.0));
+        assert_zeq!(curve.gas_temperature(60.0), f_a_1(1.0));
+        let peak = curve.gas_temperature(90.0);
+        assert!(curve.gas_temperature(120.0) < peak);
+        assert_zeq!(curve.gas_temperature(1000.0), 20.0);
+    }
+
+    #[test]
+    fn fuel_controlled_fire() {
+        let fire = ParametricFire::new(0.1, 1160.0, 100.0, 20.0 / 60.0);
+        assert!(fire.fuel_controlled());
+        assert_zeq!(fire.t_max(), 20.0 / 60.0);
+        assert!(fire.gas_temperature(20.0) > fire.gas_temperature(60.0));
+    }
+}
@@ -4,6 +4,8 @@ pub struct LoadCase {
     pub Mx: f64,
     pub My: f64,
     pub Mz: f64,
+    pub Vy: f64,
+    pub Vz: f64,
 }
 
 impl Default for LoadCase {
@@ -13,6 +15,8 @@ impl Default for LoadCase {
             Mx: 0.0,
             My: 0.0,
             Mz: 0.0,
+            Vy: 0.0,
+            Vz: 0.0,
         }
     }
 }
@@ -35,6 +39,25 @@ impl LoadCase {
         self.Mz = mz * 1_000_000.0;
         self
     }
+    #[must_use]
+    pub fn shear_kN(mut self, vy: f64, vz: f64) -> Self {
+        self.Vy = vy * 1000.0;
+        self.Vz = vz * 1000.0;
+        self
+    }
+
+    /// Every action multiplied by `factor`
+    #[must_use]
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self {
+            N: self.N * factor,
+            Mx: self.Mx * factor,
+            My: self.My * factor,
+            Mz: self.Mz * factor,
+            Vy: self.Vy * factor,
+            Vz: self.Vz * factor,
+        }
+    }
 
     #[must_use]
     pub const fn get_all(&self) -> (f64, f64, f64, f64) {
@@ -50,11 +73,15 @@ impl std::fmt::Display for LoadCase {
 {: >10.1} kN 
 {: >10.1} kNm
 {: >10.1} kNm 
-{: >10.1} kNm",
+{: >10.1} kNm
+{: >10.1} kN
+{: >10.1} kN",
             self.N / 1_000.0,
             self.Mx / 1_000_000.0,
             self.My / 1_000_000.0,
-            self.Mz / 1_000_000.0
+            self.Mz / 1_000_000.0,
+            self.Vy / 1_000.0,
+            self.Vz / 1_000.0
         )
     }
 }
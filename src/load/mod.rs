@@ -1 +1,2 @@
+pub mod fire;
 pub mod loadcase;
@@ -0,0 +1,248 @@
+use crate::erc::NSEN_1999::BucklingClass;
+use crate::{err::EmsError, LimitStateType};
+use serde::{Deserialize, Serialize};
+
+use super::{MatDef, Material};
+
+/// Alloys and tempers of extruded profiles in NS-EN 1999-1-1 Table 3.2b
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    AW5083O,
+    AW6063T6,
+    AW6005AT6,
+    AW6061T6,
+    AW6082T6,
+}
+
+impl Variant {
+    const ALL: [Self; 5] = [
+        Self::AW5083O,
+        Self::AW6063T6,
+        Self::AW6005AT6,
+        Self::AW6061T6,
+        Self::AW6082T6,
+    ];
+
+    #[must_use]
+    pub fn get(identifier: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|variant| variant.name() == identifier)
+    }
+    #[must_use]
+    pub fn variants() -> Vec<String> {
+        Self::ALL
+            .iter()
+            .map(|variant| variant.name().to_owned())
+            .collect()
+    }
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::AW5083O => "EN AW-5083 O",
+            Self::AW6063T6 => "EN AW-6063 T6",
+            Self::AW6005AT6 => "EN AW-6005A T6",
+            Self::AW6061T6 => "EN AW-6061 T6",
+            Self::AW6082T6 => "EN AW-6082 T6",
+        }
+    }
+
+    /// Rows of (maximum thickness, `f_o`, `f_u`, `rho_o_haz`, `rho_u_haz`) in mm and N/mm^2
+    #[must_use]
+    pub const fn table(&self) -> &'static [(f64, f64, f64, f64, f64)] {
+        match self {
+            Self::AW5083O => &[(200.0, 110.0, 270.0, 1.00, 1.00)],
+            Self::AW6063T6 => &[
+                (10.0, 170.0, 215.0, 0.38, 0.51),
+                (25.0, 160.0, 195.0, 0.41, 0.56),
+            ],
+            Self::AW6005AT6 => &[
+                (5.0, 225.0, 270.0, 0.51, 0.61),
+                (10.0, 215.0, 260.0, 0.53, 0.63),
+                (25.0, 200.0, 250.0, 0.58, 0.66),
+            ],
+            Self::AW6061T6 => &[(25.0, 240.0, 260.0, 0.48, 0.67)],
+            Self::AW6082T6 => &[
+                (5.0, 250.0, 290.0, 0.50, 0.64),
+                (15.0, 260.0, 310.0, 0.48, 0.60),
+            ],
+        }
+    }
+
+    /// Buckling class, Table 3.2b
+    #[must_use]
+    pub const fn buckling_class(&self) -> BucklingClass {
+        match self {
+            Self::AW5083O => BucklingClass::B,
+            Self::AW6063T6 | Self::AW6005AT6 | Self::AW6061T6 | Self::AW6082T6 => BucklingClass::A,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Aluminium {
+    fo: f64,
+    fu: f64,
+    rho_o_haz: f64,
+    rho_u_haz: f64,
+    buckling_class: BucklingClass,
+    youngs_modulus: f64,
+    density: f64,
+    gamma_m1: f64,
+    gamma_m2: f64,
+}
+
+impl Aluminium {
+    #[allow(clippy::similar_names)]
+    #[must_use]
+    pub const fn new(
+        fo: f64,
+        fu: f64,
+        rho_o_haz: f64,
+        rho_u_haz: f64,
+        buckling_class: BucklingClass,
+    ) -> Self {
+        Self {
+            fo,
+            fu,
+            rho_o_haz,
+            rho_u_haz,
+            buckling_class,
+            youngs_modulus: 70_000.0,
+            density: 2700.0,
+            gamma_m1: 1.10,
+            gamma_m2: 1.25,
+        }
+    }
+
+    /// Alloy with the strengths for an extrusion of thickness `t` in [mm]
+    ///
+    /// # Errors
+    /// Will return an error if `t` is thicker than Table 3.2b covers for the alloy
+    #[allow(clippy::similar_names)]
+    pub fn from_thickness(class: &Variant, t: f64) -> Result<Self, EmsError> {
+        let (_, fo, fu, rho_o_haz, rho_u_haz) = class
+            .table()
+            .iter()
+            .find(|(t_max, ..)| t <= *t_max)
+            .ok_or_else(|| {
+                EmsError::out_of_range_error(
+                    format!("{} is not tabulated for thickness {t} mm", class.name()),
+                    None,
+                )
+            })?;
+        Ok(Self::new(
+            *fo,
+            *fu,
+            *rho_o_haz,
+            *rho_u_haz,
+            class.buckling_class(),
+        ))
+    }
+
+    /// Reduction of the 0.2% proof strength in the heat affected zone
+    #[must_use]
+    pub const fn rho_o_haz(&self) -> f64 {
+        self.rho_o_haz
+    }
+    /// Reduction of the ultimate strength in the heat affected zone
+    #[must_use]
+    pub const fn rho_u_haz(&self) -> f64 {
+        self.rho_u_haz
+    }
+    #[must_use]
+    pub const fn buckling_class(&self) -> BucklingClass {
+        self.buckling_class
+    }
+}
+
+impl Default for Aluminium {
+    fn default() -> Self {
+        Self::new(250.0, 290.0, 0.50, 0.64, BucklingClass::A)
+    }
+}
+
+impl Material for Aluminium {
+    fn definition(&self) -> MatDef {
+        MatDef::Aluminium(self.clone())
+    }
+    fn E(&self) -> f64 {
+        self.youngs_modulus
+    }
+    fn rho(&self) -> f64 {
+        self.density
+    }
+    /// NS-EN 1999-1-1 3.2.5(1)
+    fn G(&self) -> f64 {
+        27_000.0
+    }
+    /// NS-EN 1999-1-1 3.2.5(1)
+    fn nu(&self) -> f64 {
+        0.3
+    }
+    /// NS-EN 1999-1-1 3.2.5(1)
+    fn alpha_t(&self) -> f64 {
+        23e-6
+    }
+    /// Design value of the 0.2% proof strength `f_o`
+    fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.fo / self.gamma_m0(limit_state_type)
+    }
+    fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.fu / self.gamma_m2(limit_state_type)
+    }
+    /// NS-EN 1999-1-1 uses `gamma_m1` for yielding of cross-sections as well
+    fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m1(limit_state_type)
+    }
+    fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_m1,
+        }
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_m2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn strengths_depend_on_thickness() {
+        let alu = Aluminium::from_thickness(&Variant::AW6082T6, 4.0).expect("Couldnt create alu");
+        assert_zeq!(alu.f_y(&LimitStateType::K), 250.0);
+        let alu = Aluminium::from_thickness(&Variant::AW6082T6, 10.0).expect("Couldnt create alu");
+        assert_zeq!(alu.f_y(&LimitStateType::K), 260.0);
+        assert_zeq!(alu.rho_o_haz(), 0.48);
+        assert!(Aluminium::from_thickness(&Variant::AW6082T6, 20.0).is_err());
+    }
+
+    #[test]
+    fn design_strengths_use_gamma_m1_and_gamma_m2() {
+        let alu = Aluminium::default();
+        assert_zeq!(alu.f_y(&LimitStateType::D), 250.0 / 1.1);
+        assert_zeq!(alu.f_u(&LimitStateType::D), 290.0 / 1.25);
+    }
+
+    #[test]
+    fn non_heat_treated_alloy_is_class_b() {
+        let alu = Aluminium::from_thickness(&Variant::AW5083O, 10.0).expect("Couldnt create alu");
+        assert_eq!(alu.buckling_class(), BucklingClass::B);
+        assert_zeq!(alu.rho_o_haz(), 1.0);
+    }
+
+    #[test]
+    fn alloys_can_be_found_by_name() {
+        assert_eq!(Variant::get("EN AW-6082 T6"), Some(Variant::AW6082T6));
+        for name in Variant::variants() {
+            assert!(Variant::get(&name).is_some());
+        }
+    }
+}
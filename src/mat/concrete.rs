@@ -0,0 +1,273 @@
+use crate::LimitStateType;
+use serde::{Deserialize, Serialize};
+
+use super::{MatDef, Material};
+
+/// Strength classes of NS-EN 1992-1-1 Table 3.1, named by cylinder and cube strength
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    C12,
+    C16,
+    C20,
+    C25,
+    C30,
+    C35,
+    C40,
+    C45,
+    C50,
+    C55,
+    C60,
+    C70,
+    C80,
+    C90,
+}
+
+impl Variant {
+    const ALL: [Self; 14] = [
+        Self::C12,
+        Self::C16,
+        Self::C20,
+        Self::C25,
+        Self::C30,
+        Self::C35,
+        Self::C40,
+        Self::C45,
+        Self::C50,
+        Self::C55,
+        Self::C60,
+        Self::C70,
+        Self::C80,
+        Self::C90,
+    ];
+
+    #[must_use]
+    pub fn get(identifier: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|variant| {
+            variant.name() == identifier || variant.name().split('/').next() == Some(identifier)
+        })
+    }
+    #[must_use]
+    pub fn variants() -> Vec<String> {
+        Self::ALL
+            .iter()
+            .map(|variant| variant.name().to_owned())
+            .collect()
+    }
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::C12 => "C12/15",
+            Self::C16 => "C16/20",
+            Self::C20 => "C20/25",
+            Self::C25 => "C25/30",
+            Self::C30 => "C30/37",
+            Self::C35 => "C35/45",
+            Self::C40 => "C40/50",
+            Self::C45 => "C45/55",
+            Self::C50 => "C50/60",
+            Self::C55 => "C55/67",
+            Self::C60 => "C60/75",
+            Self::C70 => "C70/85",
+            Self::C80 => "C80/95",
+            Self::C90 => "C90/105",
+        }
+    }
+    /// Characteristic cylinder strength `f_ck`
+    #[must_use]
+    pub const fn f_ck(&self) -> f64 {
+        match self {
+            Self::C12 => 12.0,
+            Self::C16 => 16.0,
+            Self::C20 => 20.0,
+            Self::C25 => 25.0,
+            Self::C30 => 30.0,
+            Self::C35 => 35.0,
+            Self::C40 => 40.0,
+            Self::C45 => 45.0,
+            Self::C50 => 50.0,
+            Self::C55 => 55.0,
+            Self::C60 => 60.0,
+            Self::C70 => 70.0,
+            Self::C80 => 80.0,
+            Self::C90 => 90.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Concrete {
+    f_ck: f64,
+    density: f64,
+    alpha_cc: f64,
+    gamma_c: f64,
+}
+
+impl Concrete {
+    /// Concrete with `alpha_cc` = 0.85 and `gamma_c` = 1.5 from the Norwegian National Annex
+    #[must_use]
+    pub const fn new(f_ck: f64) -> Self {
+        Self {
+            f_ck,
+            density: 2500.0,
+            alpha_cc: 0.85,
+            gamma_c: 1.5,
+        }
+    }
+    #[must_use]
+    pub const fn from(class: &Variant) -> Self {
+        Self::new(class.f_ck())
+    }
+
+    /// Mean compressive strength, Table 3.1
+    #[must_use]
+    pub fn f_cm(&self) -> f64 {
+        self.f_ck + 8.0
+    }
+    /// Mean tensile strength, Table 3.1
+    #[must_use]
+    pub fn f_ctm(&self) -> f64 {
+        if self.f_ck <= 50.0 {
+            0.30 * self.f_ck.powf(2.0 / 3.0)
+        } else {
+            2.12 * (self.f_cm() / 10.0).ln_1p()
+        }
+    }
+    /// Secant modulus of elasticity, Table 3.1
+    #[must_use]
+    pub fn e_cm(&self) -> f64 {
+        22_000.0 * (self.f_cm() / 10.0).powf(0.3)
+    }
+    /// Design compressive strength, 3.1.6(1)
+    #[must_use]
+    pub fn f_cd(&self) -> f64 {
+        self.alpha_cc * self.f_ck / self.gamma_c
+    }
+
+    /// Strain at reaching the maximum strength in the parabola-rectangle diagram, Table 3.1
+    #[must_use]
+    pub fn eps_c2(&self) -> f64 {
+        if self.f_ck <= 50.0 {
+            0.002
+        } else {
+            0.085f64.mul_add((self.f_ck - 50.0).powf(0.53), 2.0) / 1000.0
+        }
+    }
+    /// Ultimate strain in the parabola-rectangle diagram, Table 3.1
+    #[must_use]
+    pub fn eps_cu2(&self) -> f64 {
+        if self.f_ck <= 50.0 {
+            0.0035
+        } else {
+            35.0f64.mul_add(((90.0 - self.f_ck) / 100.0).powi(4), 2.6) / 1000.0
+        }
+    }
+    /// Exponent of the parabola, Table 3.1
+    #[must_use]
+    pub fn n(&self) -> f64 {
+        if self.f_ck <= 50.0 {
+            2.0
+        } else {
+            23.4f64.mul_add(((90.0 - self.f_ck) / 100.0).powi(4), 1.4)
+        }
+    }
+
+    /// Design stress of the parabola-rectangle diagram, 3.1.7(1), for a strain positive in
+    /// compression. Tension is carried by the reinforcement alone.
+    #[must_use]
+    pub fn stress(&self, strain: f64) -> f64 {
+        if strain <= 0.0 {
+            0.0
+        } else if strain < self.eps_c2() {
+            self.f_cd() * (1.0 - (1.0 - strain / self.eps_c2()).powf(self.n()))
+        } else {
+            self.f_cd()
+        }
+    }
+}
+
+impl Default for Concrete {
+    fn default() -> Self {
+        Self::from(&Variant::C30)
+    }
+}
+
+impl Material for Concrete {
+    fn definition(&self) -> MatDef {
+        MatDef::Concrete(self.clone())
+    }
+    fn E(&self) -> f64 {
+        self.e_cm()
+    }
+    fn rho(&self) -> f64 {
+        self.density
+    }
+    /// Uncracked concrete, NS-EN 1992-1-1 3.1.3(4)
+    fn nu(&self) -> f64 {
+        0.2
+    }
+    /// NS-EN 1992-1-1 3.1.3(5)
+    fn alpha_t(&self) -> f64 {
+        10e-6
+    }
+    /// Compressive strength, including `alpha_cc` for design values
+    fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K => self.f_ck,
+            LimitStateType::D | LimitStateType::A => {
+                self.alpha_cc * self.f_ck / self.gamma_m0(limit_state_type)
+            }
+        }
+    }
+    /// Mean tensile strength
+    fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.f_ctm() / self.gamma_m0(limit_state_type)
+    }
+    fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K => 1.00,
+            LimitStateType::D => self.gamma_c,
+            // Table 2.1N
+            LimitStateType::A => 1.20,
+        }
+    }
+    fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m0(limit_state_type)
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m0(limit_state_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn properties_follow_table_3_1() {
+        let concrete = Concrete::from(&Variant::C30);
+        assert_zeq!(concrete.f_cd(), 17.0);
+        assert_zeq!((concrete.e_cm() / 1000.0).round(), 33.0);
+        assert_zeq!((concrete.f_ctm() * 10.0).round(), 29.0);
+        let concrete = Concrete::from(&Variant::C70);
+        assert_zeq!((concrete.eps_cu2() * 1e4).round(), 27.0);
+        assert_zeq!((concrete.n() * 100.0).round(), 144.0);
+    }
+
+    #[test]
+    fn parabola_rectangle() {
+        let concrete = Concrete::default();
+        assert_zeq!(concrete.stress(-0.001), 0.0);
+        assert_zeq!(concrete.stress(0.001), 12.75);
+        assert_zeq!(concrete.stress(0.003), 17.0);
+    }
+
+    #[test]
+    fn classes_can_be_found_by_name() {
+        assert_eq!(Variant::get("C35/45"), Some(Variant::C35));
+        assert_eq!(Variant::get("C35"), Some(Variant::C35));
+        for name in Variant::variants() {
+            assert!(Variant::get(&name).is_some());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::erc::NSEN_1993_1_2::{f_3_1, k_E_theta, k_p_theta, k_y_theta, stress};
+use crate::{err::EmsError, LimitStateType};
+use serde::{Deserialize, Serialize};
+
+use super::{check_range, steel::Steel, MatDef, Material};
+
+/// Carbon steel at an elevated temperature, with properties reduced according to
+/// NS-EN 1993-1-2 3.2
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Heated {
+    pub mat: Steel,
+    /// Steel temperature in degrees Celsius
+    pub temperature: f64,
+}
+
+impl Heated {
+    #[must_use]
+    pub const fn new(mat: Steel, temperature: f64) -> Self {
+        Self { mat, temperature }
+    }
+
+    #[must_use]
+    pub fn k_y(&self) -> f64 {
+        k_y_theta(self.temperature)
+    }
+    #[must_use]
+    pub fn k_p(&self) -> f64 {
+        k_p_theta(self.temperature)
+    }
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn k_E(&self) -> f64 {
+        k_E_theta(self.temperature)
+    }
+    /// Proportional limit
+    #[must_use]
+    pub fn f_p(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.k_p() * self.mat.f_y(limit_state_type)
+    }
+    /// Characteristic stress for a strain, following the stress-strain relationship of 3.2.2
+    #[must_use]
+    pub fn stress(&self, strain: f64) -> f64 {
+        stress(
+            strain,
+            self.f_y(&LimitStateType::K),
+            self.f_p(&LimitStateType::K),
+            self.E(),
+        )
+    }
+}
+
+impl Material for Heated {
+    fn definition(&self) -> MatDef {
+        MatDef::Heated(self.clone())
+    }
+    /// Checks the ambient steel and that the temperature is within Table 3.1, as the reduced
+    /// stiffness and strength vanish at 1200 degrees Celsius
+    fn validate(&self) -> Result<(), EmsError> {
+        self.mat.validate()?;
+        check_range("theta", self.temperature, 20.0, 1200.0)
+    }
+    fn E(&self) -> f64 {
+        self.k_E() * self.mat.E()
+    }
+    fn rho(&self) -> f64 {
+        self.mat.rho()
+    }
+    fn nu(&self) -> f64 {
+        self.mat.nu()
+    }
+    /// Mean coefficient from 20 degrees Celsius, from the thermal elongation of 3.4.1.1
+    fn alpha_t(&self) -> f64 {
+        if self.temperature - 20.0 < 1.0 {
+            return self.mat.alpha_t();
+        }
+        f_3_1(self.temperature) / (self.temperature - 20.0)
+    }
+    /// Effective yield strength
+    fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.k_y() * self.mat.f_y(limit_state_type)
+    }
+    fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.k_y() * self.mat.f_u(limit_state_type)
+    }
+    fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.mat.gamma_m0(limit_state_type)
+    }
+    fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.mat.gamma_m1(limit_state_type)
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.mat.gamma_m2(limit_state_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn properties_are_reduced() {
+        let hot = Heated::new(Steel::default(), 600.0);
+        assert_zeq!(hot.f_y(&LimitStateType::K), 166.85);
+        assert_zeq!(hot.f_p(&LimitStateType::K), 63.9);
+        assert_zeq!(hot.E(), 65_100.0);
+    }
+
+    #[test]
+    fn thermal_expansion_follows_elongation() {
+        assert_zeq!(Heated::new(Steel::default(), 20.0).alpha_t(), 12e-6);
+        assert_zeq!(Heated::new(Steel::default(), 600.0).alpha_t(), 1.448e-5);
+    }
+
+    #[test]
+    fn trait_objects_round_trip_up_to_1200_degrees() {
+        let mat: Box<dyn Material> = Box::new(Heated::new(Steel::default(), 1200.0));
+        let text = serde_json::to_string(&mat).expect("Couldnt serialise");
+        let back: Box<dyn Material> = serde_json::from_str(&text).expect(&text);
+        assert_zeq!(back.f_y(&LimitStateType::K), 0.0);
+        let molten = text.replace("1200.0", "1300.0");
+        assert!(serde_json::from_str::<Box<dyn Material>>(&molten).is_err());
+    }
+
+    #[test]
+    fn room_temperature_is_unchanged() {
+        let cold = Heated::new(Steel::default(), 20.0);
+        assert_zeq!(
+            cold.f_y(&LimitStateType::D),
+            Steel::default().f_y(&LimitStateType::D)
+        );
+        assert_zeq!(cold.stress(0.001), 210.0);
+        assert_zeq!(cold.stress(0.05), 355.0);
+    }
+}
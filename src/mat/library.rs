@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{aluminium, concrete, stainless, steel, timber, MatDef, Material};
+use crate::err::EmsError;
+
+/// Named materials, such as the approved material list of a project, which may be extended
+/// with grades registered at runtime or read from JSON and RON files
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MatLib {
+    materials: BTreeMap<String, MatDef>,
+}
+
+impl MatLib {
+    /// Library with the built-in steel, stainless steel, aluminium, timber and concrete grades,
+    /// named as in their `Variant::variants()`
+    ///
+    /// Stainless grades are cold rolled strip and aluminium alloys take the strengths of their
+    /// thinnest row, like carbon steel up to 40 mm
+    #[must_use]
+    pub fn new() -> Self {
+        let mut materials = BTreeMap::new();
+        for name in steel::Variant::variants() {
+            if let Some(variant) = steel::Variant::get(&name) {
+                materials.insert(name, MatDef::Steel(steel::Steel::from(&variant)));
+            }
+        }
+        for name in stainless::Variant::variants() {
+            if let Some(variant) = stainless::Variant::get(&name) {
+                let form = stainless::ProductForm::ColdRolledStrip;
+                materials.insert(
+                    name,
+                    MatDef::Stainless(stainless::Stainless::from(&variant, &form)),
+                );
+            }
+        }
+        for name in aluminium::Variant::variants() {
+            if let Some(variant) = aluminium::Variant::get(&name) {
+                if let Ok(mat) = aluminium::Aluminium::from_thickness(&variant, 0.0) {
+                    materials.insert(name, MatDef::Aluminium(mat));
+                }
+            }
+        }
+        for name in timber::Variant::variants() {
+            if let Some(variant) = timber::Variant::get(&name) {
+                materials.insert(name, MatDef::Timber(timber::Timber::from(&variant)));
+            }
+        }
+        for name in concrete::Variant::variants() {
+            if let Some(variant) = concrete::Variant::get(&name) {
+                materials.insert(name, MatDef::Concrete(concrete::Concrete::from(&variant)));
+            }
+        }
+        Self { materials }
+    }
+    /// Library without any materials
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Adds `def` under `name`, replacing any material of the same name
+    ///
+    /// # Errors
+    /// Will return an error if the material has properties outside their physical ranges
+    pub fn register(&mut self, name: &str, def: MatDef) -> Result<(), EmsError> {
+        Box::<dyn Material>::from(def.clone())
+            .validate()
+            .map_err(|e| EmsError::out_of_range_error(format!("{name}: {e}"), Some(Box::new(e))))?;
+        self.materials.insert(name.to_owned(), def);
+        Ok(())
+    }
+    fn register_all(&mut self, materials: BTreeMap<String, MatDef>) -> Result<(), EmsError> {
+        for (name, def) in materials {
+            self.register(&name, def)?;
+        }
+        Ok(())
+    }
+
+    /// Registers every material of a JSON object mapping names to materials, e.g.
+    /// `{"S355 client": {"Steel": {...}}}`
+    ///
+    /// # Errors
+    /// Will return an error if the text cannot be parsed or a material is out of range
+    pub fn load_json(&mut self, text: &str) -> Result<(), EmsError> {
+        let materials = serde_json::from_str(text).map_err(|e| {
+            EmsError::database_error(
+                "Could not parse the material list".to_owned(),
+                Some(Box::new(e)),
+            )
+        })?;
+        self.register_all(materials)
+    }
+    /// Registers every material of a RON map from names to materials, e.g.
+    /// `{"S355 client": Steel((...))}`
+    ///
+    /// # Errors
+    /// Will return an error if the text cannot be parsed or a material is out of range
+    pub fn load_ron(&mut self, text: &str) -> Result<(), EmsError> {
+        let materials = ron::from_str(text).map_err(|e| {
+            EmsError::database_error(
+                "Could not parse the material list".to_owned(),
+                Some(Box::new(e)),
+            )
+        })?;
+        self.register_all(materials)
+    }
+    /// Registers the materials of a ".json" or ".ron" file
+    ///
+    /// # Errors
+    /// Will return an error if the file cannot be read, has another extension, or cannot be
+    /// loaded by [`MatLib::load_json`] or [`MatLib::load_ron`]
+    pub fn load_file(&mut self, path: &Path) -> Result<(), EmsError> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            EmsError::file_not_found_error(
+                format!("Could not read {}", path.display()),
+                Some(Box::new(e)),
+            )
+        })?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => self.load_json(&text),
+            Some("ron") => self.load_ron(&text),
+            _ => Err(EmsError::file_not_found_error(
+                format!("{} is neither a .json nor a .ron file", path.display()),
+                None,
+            )),
+        }
+    }
+
+    #[must_use]
+    pub fn materials(&self) -> Vec<String> {
+        self.materials.keys().cloned().collect()
+    }
+
+    /// # Errors
+    /// Will return an error if there is no material named `name`
+    pub fn get(&self, name: &str) -> Result<Box<dyn Material>, EmsError> {
+        self.def(name).map(|def| def.clone().into())
+    }
+    /// # Errors
+    /// Will return an error if there is no material named `name`, or if it is not a steel
+    pub fn steel(&self, name: &str) -> Result<steel::Steel, EmsError> {
+        match self.def(name)? {
+            MatDef::Steel(mat) => Ok(mat.clone()),
+            _ => Err(EmsError::field_not_found_error(
+                format!("{name} is not a structural steel"),
+                None,
+            )),
+        }
+    }
+    fn def(&self, name: &str) -> Result<&MatDef, EmsError> {
+        self.materials.get(name).ok_or_else(|| {
+            EmsError::field_not_found_error(
+                format!("Could not find the material \"{name}\" in the library"),
+                None,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+    use crate::LimitStateType;
+
+    const CLIENT: &str = r#"{
+        "S355 client": {"Steel": {"fy": 355.0, "fu": 510.0, "youngs_modulus": 210000.0,
+            "density": 7850.0, "gamma_m0": 1.05, "gamma_m1": 1.10, "gamma_m2": 1.25,
+            "gamma_m_fi": 1.0}}
+    }"#;
+
+    #[test]
+    fn built_in_grades_are_available() {
+        let lib = MatLib::new();
+        let steel = lib.steel("S355").expect("Missing grade");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 355.0);
+        assert!(lib.get("C24").is_ok());
+        assert!(lib.get("C30/37").is_ok());
+        assert!(lib.get("1.4301").is_ok());
+        assert_zeq!(
+            lib.get("EN AW-6082 T6")
+                .expect("Missing grade")
+                .f_y(&LimitStateType::K),
+            250.0
+        );
+        assert!(lib.steel("C24").is_err());
+        assert!(lib.get("S999").is_err());
+    }
+
+    #[test]
+    fn grades_can_be_registered_from_json_and_ron() {
+        let mut lib = MatLib::empty();
+        lib.load_json(CLIENT).expect("Couldnt load");
+        assert_eq!(lib.materials(), vec!["S355 client".to_owned()]);
+        let steel = lib.steel("S355 client").expect("Missing grade");
+        assert_zeq!(steel.gamma_m1(&LimitStateType::D), 1.10);
+
+        let text = ron::to_string(&lib.materials).expect("Couldnt serialise");
+        let mut other = MatLib::empty();
+        other.load_ron(&text).expect(&text);
+        assert_eq!(other.materials(), lib.materials());
+    }
+
+    #[test]
+    fn trait_objects_round_trip() {
+        let lib = MatLib::new();
+        for name in ["S355", "1.4301", "EN AW-6082 T6", "C24", "C30/37"] {
+            let mat = lib.get(name).expect("Missing grade");
+            let text = serde_json::to_string(&mat).expect("Couldnt serialise");
+            let back: Box<dyn Material> = serde_json::from_str(&text).expect(&text);
+            assert_zeq!(back.E(), mat.E());
+        }
+        let bad = r#"{"Reinforcement": {"f_yk": -500.0, "eps_uk": 0.075,
+            "youngs_modulus": 200000.0, "density": 7850.0, "gamma_s": 1.15}}"#;
+        assert!(serde_json::from_str::<Box<dyn Material>>(bad).is_err());
+    }
+
+    #[test]
+    fn out_of_range_materials_are_rejected() {
+        let mut lib = MatLib::empty();
+        let weak = CLIENT.replace("\"fu\": 510.0", "\"fu\": 300.0");
+        assert!(lib.load_json(&weak).is_err());
+        let heavy = CLIENT.replace("7850.0", "78500.0");
+        assert!(lib.load_json(&heavy).is_err());
+        assert!(lib.materials().is_empty());
+    }
+}
@@ -1,27 +1,132 @@
-use crate::LimitStateType;
+use crate::{err::EmsError, LimitStateType};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{json, Value};
 
+pub mod aluminium;
+pub mod concrete;
+pub mod heated;
+pub mod library;
+pub mod reinforcement;
+pub mod stainless;
+pub mod stressstrain;
+pub mod timber;
 pub mod steel;
 
+/// Serialisable definition of any material, tagged by its type so a `Box<dyn Material>` can
+/// be written to and read back from JSON or RON
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MatDef {
+    Steel(steel::Steel),
+    Stainless(stainless::Stainless),
+    Aluminium(aluminium::Aluminium),
+    Timber(timber::Timber),
+    Concrete(concrete::Concrete),
+    Reinforcement(reinforcement::Reinforcement),
+    Heated(heated::Heated),
+}
+
+impl From<MatDef> for Box<dyn Material> {
+    fn from(def: MatDef) -> Self {
+        match def {
+            MatDef::Steel(mat) => Box::new(mat),
+            MatDef::Stainless(mat) => Box::new(mat),
+            MatDef::Aluminium(mat) => Box::new(mat),
+            MatDef::Timber(mat) => Box::new(mat),
+            MatDef::Concrete(mat) => Box::new(mat),
+            MatDef::Reinforcement(mat) => Box::new(mat),
+            MatDef::Heated(mat) => Box::new(mat),
+        }
+    }
+}
+
+impl Serialize for dyn Material {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.definition().serialize(serializer)
+    }
+}
+
+/// Rejects materials with properties outside their physical ranges
+impl<'de> Deserialize<'de> for Box<dyn Material> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mat = Self::from(MatDef::deserialize(deserializer)?);
+        mat.validate().map_err(de::Error::custom)?;
+        Ok(mat)
+    }
+}
+
+/// Returns an error unless `min <= value <= max`
+///
+/// # Errors
+/// Will return an error if `value` is outside the range or not a number
+pub fn check_range(name: &str, value: f64, min: f64, max: f64) -> Result<(), EmsError> {
+    if (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(EmsError::out_of_range_error(
+            format!("{name} = {value} is outside the physical range [{min}, {max}]"),
+            None,
+        ))
+    }
+}
+
+/// Ranges every material must satisfy, with strengths in N/mm^2 and density in kg/m^3
+///
+/// # Errors
+/// Will return an error for the first property outside its range
+pub fn check_physical<M: Material + ?Sized>(mat: &M) -> Result<(), EmsError> {
+    check_range("E", mat.E(), 1.0, 300_000.0)?;
+    check_range("rho", mat.rho(), 1.0, 20_000.0)?;
+    check_range("nu", mat.nu(), 0.0, 0.5)?;
+    check_range("alpha_T", mat.alpha_t(), 0.0, 50e-6)?;
+    check_range("f_y", mat.f_y(&LimitStateType::K), 1e-3, 2_000.0)?;
+    check_range("f_u", mat.f_u(&LimitStateType::K), 1e-3, 2_500.0)?;
+    check_range("gamma_m0", mat.gamma_m0(&LimitStateType::D), 1.0, 2.0)?;
+    check_range("gamma_m1", mat.gamma_m1(&LimitStateType::D), 1.0, 2.0)?;
+    check_range("gamma_m2", mat.gamma_m2(&LimitStateType::D), 1.0, 2.0)
+}
+
 pub trait Material {
+    /// Definition of the material that can be serialised and turned back into a material
+    fn definition(&self) -> MatDef;
+    /// Checks that the properties are within their physical ranges
+    ///
+    /// # Errors
+    /// Will return an error for the first property outside its range
+    fn validate(&self) -> Result<(), EmsError> {
+        check_physical(self)
+    }
     #[allow(non_snake_case)]
     fn E(&self) -> f64;
     fn rho(&self) -> f64;
+    /// Shear modulus in N/mm^2
+    #[allow(non_snake_case)]
+    fn G(&self) -> f64 {
+        self.E() / (2.0 * (1.0 + self.nu()))
+    }
+    /// Poisson's ratio in the elastic range
+    fn nu(&self) -> f64;
+    /// Coefficient of linear thermal expansion in 1/K
+    fn alpha_t(&self) -> f64;
     fn f_y(&self, limit_state_type: &LimitStateType) -> f64;
     fn f_u(&self, limit_state_type: &LimitStateType) -> f64;
     fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64;
     fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64;
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64;
 
     fn json(&self) -> Value {
         let jsonout = json!({
             "E": self.E(),
             "rho": self.rho(),
+            "G": self.G(),
+            "nu": self.nu(),
+            "alpha_T": self.alpha_t(),
             "f_y":  self.f_y(&LimitStateType::K),
             "f_y_d":  self.f_y(&LimitStateType::D),
             "f_u": self.f_u(&LimitStateType::K),
             "f_u_d": self.f_u(&LimitStateType::D),
             "gamma_m0": self.gamma_m0(&LimitStateType::D),
             "gamma_m1": self.gamma_m1(&LimitStateType::D),
+            "gamma_m2": self.gamma_m2(&LimitStateType::D),
         });
         jsonout
     }
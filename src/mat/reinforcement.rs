@@ -0,0 +1,106 @@
+use crate::LimitStateType;
+use serde::{Deserialize, Serialize};
+
+use super::{MatDef, Material};
+
+/// Reinforcing steel with the horizontal top branch of NS-EN 1992-1-1 3.2.7(2)b
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Reinforcement {
+    f_yk: f64,
+    eps_uk: f64,
+    youngs_modulus: f64,
+    density: f64,
+    gamma_s: f64,
+}
+
+impl Reinforcement {
+    #[must_use]
+    pub const fn new(f_yk: f64, eps_uk: f64) -> Self {
+        Self {
+            f_yk,
+            eps_uk,
+            youngs_modulus: 200_000.0,
+            density: 7850.0,
+            gamma_s: 1.15,
+        }
+    }
+    /// B500NC according to NS 3576-3, ductility class C
+    #[must_use]
+    pub const fn b500nc() -> Self {
+        Self::new(500.0, 0.075)
+    }
+
+    #[must_use]
+    pub fn f_yd(&self) -> f64 {
+        self.f_yk / self.gamma_s
+    }
+    /// Design limit of the strain, 3.2.7(2) with the recommended 0.9 `eps_uk`
+    #[must_use]
+    pub fn eps_ud(&self) -> f64 {
+        0.9 * self.eps_uk
+    }
+    /// Design stress for a strain positive in compression
+    #[must_use]
+    pub fn stress(&self, strain: f64) -> f64 {
+        (self.youngs_modulus * strain).clamp(-self.f_yd(), self.f_yd())
+    }
+}
+
+impl Default for Reinforcement {
+    fn default() -> Self {
+        Self::b500nc()
+    }
+}
+
+impl Material for Reinforcement {
+    fn definition(&self) -> MatDef {
+        MatDef::Reinforcement(self.clone())
+    }
+    fn E(&self) -> f64 {
+        self.youngs_modulus
+    }
+    fn rho(&self) -> f64 {
+        self.density
+    }
+    fn nu(&self) -> f64 {
+        0.3
+    }
+    /// Taken equal to that of the concrete, NS-EN 1992-1-1 3.1.3(5)
+    fn alpha_t(&self) -> f64 {
+        10e-6
+    }
+    fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.f_yk / self.gamma_m0(limit_state_type)
+    }
+    /// Tensile strength with k = 1.08 for ductility class C
+    fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
+        1.08 * self.f_yk / self.gamma_m0(limit_state_type)
+    }
+    fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            // Table 2.1N gives 1.0 for accidental design situations
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_s,
+        }
+    }
+    fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m0(limit_state_type)
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m0(limit_state_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn stress_is_capped_at_f_yd() {
+        let rebar = Reinforcement::default();
+        assert_zeq!(rebar.stress(0.001), 200.0);
+        assert_zeq!(rebar.stress(-0.01), -434.782_608);
+        assert_zeq!(rebar.eps_ud(), 0.0675);
+    }
+}
@@ -0,0 +1,351 @@
+use crate::erc::NSEN_1993::{f_6_49, CompressionPart};
+use crate::erc::NSEN_1993_1_4::{class_limits, epsilon, f_5_3, BucklingForm};
+use crate::LimitStateType;
+use serde::{Deserialize, Serialize};
+
+use super::{MatDef, Material};
+
+/// Stainless steel grades of NS-EN 1993-1-4 Table 2.1, named by their material number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    // Ferritic
+    EN1_4003,
+    // Austenitic
+    EN1_4301,
+    EN1_4307,
+    EN1_4401,
+    EN1_4404,
+    EN1_4571,
+    // Duplex
+    EN1_4362,
+    EN1_4462,
+}
+
+/// Product form, which decides the strengths in NS-EN 1993-1-4 Table 2.1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProductForm {
+    ColdRolledStrip,
+    HotRolledStrip,
+    HotRolledPlate,
+    Bars,
+}
+
+impl Variant {
+    const ALL: [Self; 8] = [
+        Self::EN1_4003,
+        Self::EN1_4301,
+        Self::EN1_4307,
+        Self::EN1_4401,
+        Self::EN1_4404,
+        Self::EN1_4571,
+        Self::EN1_4362,
+        Self::EN1_4462,
+    ];
+
+    #[must_use]
+    pub fn get(identifier: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|variant| variant.name() == identifier)
+    }
+    #[must_use]
+    pub fn variants() -> Vec<String> {
+        Self::ALL.iter().map(Self::name).collect()
+    }
+    /// Material number, e.g. "1.4301"
+    #[must_use]
+    pub fn name(&self) -> String {
+        format!("{self:?}")
+            .trim_start_matches("EN")
+            .replace('_', ".")
+    }
+
+    /// Nominal (`f_y`, `f_u`) in N/mm^2 for the given product form
+    #[allow(clippy::match_same_arms)]
+    #[must_use]
+    pub const fn strengths(&self, form: &ProductForm) -> (f64, f64) {
+        match (self, form) {
+            (Self::EN1_4003, ProductForm::ColdRolledStrip | ProductForm::HotRolledStrip) => {
+                (280.0, 450.0)
+            }
+            (Self::EN1_4003, ProductForm::HotRolledPlate) => (250.0, 450.0),
+            (Self::EN1_4003, ProductForm::Bars) => (260.0, 450.0),
+            (Self::EN1_4301, ProductForm::ColdRolledStrip) => (230.0, 540.0),
+            (Self::EN1_4301, ProductForm::HotRolledStrip | ProductForm::HotRolledPlate) => {
+                (210.0, 520.0)
+            }
+            (Self::EN1_4301, ProductForm::Bars) => (190.0, 500.0),
+            (Self::EN1_4307, ProductForm::ColdRolledStrip) => (220.0, 520.0),
+            (Self::EN1_4307, ProductForm::HotRolledStrip) => (200.0, 520.0),
+            (Self::EN1_4307, ProductForm::HotRolledPlate) => (200.0, 500.0),
+            (Self::EN1_4307, ProductForm::Bars) => (175.0, 500.0),
+            (Self::EN1_4401 | Self::EN1_4404, ProductForm::ColdRolledStrip) => (240.0, 530.0),
+            (Self::EN1_4401 | Self::EN1_4404, ProductForm::HotRolledStrip) => (220.0, 530.0),
+            (Self::EN1_4401 | Self::EN1_4404, ProductForm::HotRolledPlate) => (220.0, 520.0),
+            (Self::EN1_4401 | Self::EN1_4404 | Self::EN1_4571, ProductForm::Bars) => (200.0, 500.0),
+            (Self::EN1_4571, ProductForm::ColdRolledStrip) => (240.0, 540.0),
+            (Self::EN1_4571, ProductForm::HotRolledStrip) => (220.0, 540.0),
+            (Self::EN1_4571, ProductForm::HotRolledPlate) => (220.0, 520.0),
+            (Self::EN1_4362, ProductForm::ColdRolledStrip) => (420.0, 600.0),
+            (Self::EN1_4362, ProductForm::HotRolledStrip) => (400.0, 650.0),
+            (Self::EN1_4362, ProductForm::HotRolledPlate) => (400.0, 630.0),
+            (Self::EN1_4362, ProductForm::Bars) => (400.0, 600.0),
+            (Self::EN1_4462, ProductForm::ColdRolledStrip) => (500.0, 700.0),
+            (Self::EN1_4462, ProductForm::HotRolledStrip) => (460.0, 700.0),
+            (Self::EN1_4462, ProductForm::HotRolledPlate) => (460.0, 640.0),
+            (Self::EN1_4462, ProductForm::Bars) => (450.0, 650.0),
+        }
+    }
+
+    /// Ramberg-Osgood exponent n in the rolling direction, NS-EN 1993-1-4 Table C.1
+    #[must_use]
+    pub const fn n(&self) -> f64 {
+        match self {
+            Self::EN1_4003 => 7.0,
+            Self::EN1_4301 | Self::EN1_4307 | Self::EN1_4401 | Self::EN1_4404 | Self::EN1_4571 => {
+                6.0
+            }
+            Self::EN1_4362 | Self::EN1_4462 => 5.0,
+        }
+    }
+
+    /// Coefficient of thermal expansion in 1/K between 20 and 100 degrees Celsius, NS-EN 10088-1
+    #[must_use]
+    pub const fn thermal_expansion(&self) -> f64 {
+        match self {
+            Self::EN1_4003 => 10.4e-6,
+            Self::EN1_4301 | Self::EN1_4307 | Self::EN1_4401 | Self::EN1_4404 | Self::EN1_4571 => {
+                16e-6
+            }
+            Self::EN1_4362 | Self::EN1_4462 => 13e-6,
+        }
+    }
+
+    /// Density in [kg/m^3]
+    #[must_use]
+    pub const fn density(&self) -> f64 {
+        match self {
+            Self::EN1_4003 => 7700.0,
+            Self::EN1_4301 | Self::EN1_4307 => 7900.0,
+            Self::EN1_4401 | Self::EN1_4404 | Self::EN1_4571 => 8000.0,
+            Self::EN1_4362 | Self::EN1_4462 => 7800.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Stainless {
+    fy: f64,
+    fu: f64,
+    youngs_modulus: f64,
+    density: f64,
+    n: f64,
+    thermal_expansion: f64,
+    gamma_m0: f64,
+    gamma_m1: f64,
+    gamma_m2: f64,
+}
+
+impl Stainless {
+    #[must_use]
+    pub const fn new(fy: f64, fu: f64, youngs_modulus: f64, density: f64, n: f64) -> Self {
+        Self {
+            fy,
+            fu,
+            youngs_modulus,
+            density,
+            n,
+            thermal_expansion: 16e-6,
+            gamma_m0: 1.10,
+            gamma_m1: 1.10,
+            gamma_m2: 1.25,
+        }
+    }
+
+    #[must_use]
+    pub const fn from(class: &Variant, form: &ProductForm) -> Self {
+        let (fy, fu) = class.strengths(form);
+        Self {
+            thermal_expansion: class.thermal_expansion(),
+            ..Self::new(fy, fu, 200_000.0, class.density(), class.n())
+        }
+    }
+
+    /// Material factor epsilon used in the width-to-thickness limits
+    #[must_use]
+    pub fn epsilon(&self) -> f64 {
+        epsilon(self.fy, self.youngs_modulus)
+    }
+
+    /// Maximum width-to-thickness ratios for Class 1, 2 and 3
+    #[must_use]
+    pub fn class_limits(&self, part: &CompressionPart, welded: bool) -> [f64; 3] {
+        class_limits(part, welded, self.epsilon())
+    }
+
+    /// Buckling reduction factor khi for the non-dimensional slenderness `lambda`
+    #[must_use]
+    pub fn khi(&self, form: &BucklingForm, lambda: f64) -> f64 {
+        let (alpha, lambda_0) = form.imperfection();
+        if lambda <= lambda_0 {
+            return 1.0;
+        }
+        f_6_49(f_5_3(alpha, lambda, lambda_0), lambda)
+    }
+
+    /// Secant modulus at the 0.2% proof stress, NS-EN 1993-1-4 C.2
+    #[must_use]
+    pub fn e_02(&self) -> f64 {
+        self.youngs_modulus / (0.002 * self.n * self.youngs_modulus / self.fy + 1.0)
+    }
+
+    /// Strain at the stress `sigma` in N/mm^2, NS-EN 1993-1-4 C.1 and C.2
+    #[must_use]
+    pub fn strain(&self, sigma: f64) -> f64 {
+        let sign = sigma.signum();
+        let sigma = sigma.abs();
+        let strain = if sigma <= self.fy {
+            (sigma / self.fy)
+                .powf(self.n)
+                .mul_add(0.002, sigma / self.youngs_modulus)
+        } else {
+            let m = 3.5f64.mul_add(self.fy / self.fu, 1.0);
+            let eps_u = 1.0 - self.fy / self.fu;
+            eps_u.mul_add(
+                ((sigma - self.fy) / (self.fu - self.fy)).powf(m),
+                0.002 + self.fy / self.youngs_modulus + (sigma - self.fy) / self.e_02(),
+            )
+        };
+        sign * strain
+    }
+
+    /// Stress at the strain `strain`, found by inverting [`Stainless::strain`]
+    #[must_use]
+    pub fn stress(&self, strain: f64) -> f64 {
+        let (mut low, mut high) = (0.0, self.fu);
+        if strain.abs() >= self.strain(self.fu) {
+            return strain.signum() * self.fu;
+        }
+        for _ in 0..100 {
+            let mid = 0.5 * (low + high);
+            if self.strain(mid) < strain.abs() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        strain.signum() * 0.5 * (low + high)
+    }
+}
+
+impl Default for Stainless {
+    fn default() -> Self {
+        Self::from(&Variant::EN1_4301, &ProductForm::ColdRolledStrip)
+    }
+}
+
+impl Material for Stainless {
+    fn definition(&self) -> MatDef {
+        MatDef::Stainless(self.clone())
+    }
+    fn E(&self) -> f64 {
+        self.youngs_modulus
+    }
+    fn rho(&self) -> f64 {
+        self.density
+    }
+    /// Elastic range, as for carbon steel
+    fn nu(&self) -> f64 {
+        0.3
+    }
+    fn alpha_t(&self) -> f64 {
+        self.thermal_expansion
+    }
+    fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.fy / self.gamma_m0(limit_state_type)
+    }
+    fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.fu / self.gamma_m2(limit_state_type)
+    }
+    fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_m0,
+        }
+    }
+    fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_m1,
+        }
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_m2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn can_create_expected_grade() {
+        let steel = Stainless::from(&Variant::EN1_4462, &ProductForm::HotRolledPlate);
+        assert_zeq!(steel.f_y(&LimitStateType::K), 460.0);
+        assert_zeq!(steel.f_y(&LimitStateType::D), 460.0 / 1.1);
+        assert_zeq!(steel.f_u(&LimitStateType::D), 640.0 / 1.25);
+        assert_zeq!(steel.E(), 200_000.0);
+        assert_zeq!(steel.alpha_t(), 13e-6);
+    }
+
+    #[test]
+    fn grades_can_be_found_by_material_number() {
+        assert_eq!(Variant::get("1.4401"), Some(Variant::EN1_4401));
+        for name in Variant::variants() {
+            assert!(Variant::get(&name).is_some());
+        }
+    }
+
+    #[test]
+    fn proof_strain_at_yield() {
+        let steel = Stainless::default();
+        assert_zeq!(steel.strain(230.0), 0.002 + 230.0 / 200_000.0);
+        assert_zeq!(steel.strain(0.0), 0.0);
+        assert_zeq!(steel.strain(-230.0), -steel.strain(230.0));
+    }
+
+    #[test]
+    fn stress_inverts_strain() {
+        let steel = Stainless::default();
+        for sigma in [50.0, 150.0, 230.0, 400.0] {
+            assert_zeq!(steel.stress(steel.strain(sigma)), sigma);
+        }
+        assert_zeq!(steel.stress(1.0), 540.0);
+    }
+
+    #[test]
+    fn stress_strain_curve_is_rounded_below_proof_stress() {
+        let steel = Stainless::default();
+        let linear = 150.0 / steel.E();
+        assert!(steel.strain(150.0) > linear);
+    }
+
+    #[test]
+    fn buckling_plateau_depends_on_form() {
+        let steel = Stainless::default();
+        assert_zeq!(steel.khi(&BucklingForm::Hollow, 0.3), 1.0);
+        assert!(steel.khi(&BucklingForm::WeldedOpenMinor, 0.3) < 1.0);
+        assert_zeq!(steel.khi(&BucklingForm::Hollow, 1.0), 0.585_209);
+    }
+
+    #[test]
+    fn class_limits_scale_with_epsilon() {
+        let steel = Stainless::default();
+        let limits = steel.class_limits(&CompressionPart::InternalCompression, false);
+        assert_zeq!(limits[0], 25.7 * steel.epsilon());
+    }
+}
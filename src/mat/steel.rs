@@ -1,36 +1,172 @@
-use crate::LimitStateType;
+use crate::{crs::CrossSection, erc::annex::NationalAnnex, err::EmsError, LimitStateType};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use super::Material;
+use super::{check_physical, check_range, MatDef, Material};
 
+/// Manufacturing route of structural hollow sections, which decides the product standard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hollow {
+    /// EN 10210-1
+    HotFinished,
+    /// EN 10219-1
+    ColdFormed,
+}
+
+/// Structural steel grades of NS-EN 1993-1-1 Table 3.1
+///
+/// The N and M grades also cover the NL and ML grades, which share their strengths
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Variant {
+    // EN 10025-2
     S235,
     S275,
     S355,
     S450,
+    // EN 10025-3
+    S275N,
+    S355N,
+    S420N,
+    S460N,
+    // EN 10025-4
+    S275M,
+    S355M,
+    S420M,
+    S460M,
+    // EN 10210-1 and EN 10219-1
+    S235H(Hollow),
+    S275H(Hollow),
+    S355H(Hollow),
+    S275NH(Hollow),
+    S355NH(Hollow),
+    S460NH(Hollow),
+    // EN 10210-1
+    S420NH,
+    // EN 10219-1
+    S275MH,
+    S355MH,
+    S420MH,
+    S460MH,
 }
 impl Variant {
+    const ALL: [Self; 29] = [
+        Self::S235,
+        Self::S275,
+        Self::S355,
+        Self::S450,
+        Self::S275N,
+        Self::S355N,
+        Self::S420N,
+        Self::S460N,
+        Self::S275M,
+        Self::S355M,
+        Self::S420M,
+        Self::S460M,
+        Self::S235H(Hollow::HotFinished),
+        Self::S275H(Hollow::HotFinished),
+        Self::S355H(Hollow::HotFinished),
+        Self::S275NH(Hollow::HotFinished),
+        Self::S355NH(Hollow::HotFinished),
+        Self::S460NH(Hollow::HotFinished),
+        Self::S420NH,
+        Self::S235H(Hollow::ColdFormed),
+        Self::S275H(Hollow::ColdFormed),
+        Self::S355H(Hollow::ColdFormed),
+        Self::S275NH(Hollow::ColdFormed),
+        Self::S355NH(Hollow::ColdFormed),
+        Self::S460NH(Hollow::ColdFormed),
+        Self::S275MH,
+        Self::S355MH,
+        Self::S420MH,
+        Self::S460MH,
+    ];
+
+    /// Hollow grades default to hot-finished, cold-formed ones are identified by a trailing
+    /// "(EN 10219)", e.g. "S355NH (EN 10219)"
     #[must_use]
     pub fn get(identifier: &str) -> Option<Self> {
-        match identifier {
-            "S235" => Some(Self::S235),
-            "S275" => Some(Self::S275),
-            "S355" => Some(Self::S355),
-            "S450" => Some(Self::S450),
-            _ => None,
-        }
+        Self::ALL
+            .into_iter()
+            .find(|variant| variant.name() == identifier)
     }
     #[must_use]
     pub fn variants() -> Vec<String> {
-        vec![
-            String::from("S235"),
-            String::from("S275"),
-            String::from("S355"),
-            String::from("S450"),
-        ]
+        Self::ALL.iter().map(Self::name).collect()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> String {
+        let debug = format!("{self:?}");
+        let base = debug.split('(').next().unwrap_or_default();
+        match self {
+            Self::S235H(Hollow::ColdFormed)
+            | Self::S275H(Hollow::ColdFormed)
+            | Self::S355H(Hollow::ColdFormed)
+            | Self::S275NH(Hollow::ColdFormed)
+            | Self::S355NH(Hollow::ColdFormed)
+            | Self::S460NH(Hollow::ColdFormed) => format!("{base} (EN 10219)"),
+            _ => base.to_owned(),
+        }
+    }
+
+    /// Rows of (maximum thickness, `f_y`, `f_u`) in mm and N/mm^2 from NS-EN 1993-1-1 Table 3.1
+    #[allow(clippy::match_same_arms)]
+    #[must_use]
+    pub const fn table(&self) -> &'static [(f64, f64, f64)] {
+        match self {
+            Self::S235 => &[(40.0, 235.0, 360.0), (80.0, 215.0, 360.0)],
+            Self::S275 => &[(40.0, 275.0, 430.0), (80.0, 255.0, 410.0)],
+            Self::S355 => &[(40.0, 355.0, 510.0), (80.0, 335.0, 470.0)],
+            // Table 3.1 rounds the EN 10025-2 yield strengths of S450 down to 440 MPa
+            Self::S450 => &[(40.0, 440.0, 550.0), (80.0, 410.0, 550.0)],
+            Self::S275N => &[(40.0, 275.0, 370.0), (80.0, 255.0, 370.0)],
+            Self::S355N => &[(40.0, 355.0, 470.0), (80.0, 335.0, 470.0)],
+            Self::S420N => &[(40.0, 420.0, 520.0), (80.0, 390.0, 520.0)],
+            Self::S460N => &[(40.0, 460.0, 540.0), (80.0, 430.0, 540.0)],
+            Self::S275M => &[(40.0, 275.0, 370.0), (80.0, 255.0, 360.0)],
+            Self::S355M => &[(40.0, 355.0, 470.0), (80.0, 335.0, 450.0)],
+            Self::S420M => &[(40.0, 420.0, 520.0), (80.0, 390.0, 500.0)],
+            Self::S460M => &[(40.0, 460.0, 540.0), (80.0, 430.0, 530.0)],
+            Self::S235H(Hollow::HotFinished) => &[(40.0, 235.0, 360.0), (65.0, 215.0, 340.0)],
+            Self::S275H(Hollow::HotFinished) => &[(40.0, 275.0, 430.0), (65.0, 255.0, 410.0)],
+            Self::S355H(Hollow::HotFinished) => &[(40.0, 355.0, 510.0), (65.0, 335.0, 490.0)],
+            Self::S275NH(Hollow::HotFinished) => &[(40.0, 275.0, 370.0), (65.0, 255.0, 370.0)],
+            Self::S355NH(Hollow::HotFinished) => &[(40.0, 355.0, 470.0), (65.0, 335.0, 470.0)],
+            Self::S460NH(Hollow::HotFinished) => &[(40.0, 460.0, 560.0), (65.0, 430.0, 550.0)],
+            Self::S420NH => &[(40.0, 420.0, 540.0), (65.0, 390.0, 520.0)],
+            Self::S235H(Hollow::ColdFormed) => &[(40.0, 235.0, 360.0)],
+            Self::S275H(Hollow::ColdFormed) => &[(40.0, 275.0, 430.0)],
+            Self::S355H(Hollow::ColdFormed) => &[(40.0, 355.0, 510.0)],
+            Self::S275NH(Hollow::ColdFormed) => &[(40.0, 275.0, 370.0)],
+            Self::S355NH(Hollow::ColdFormed) => &[(40.0, 355.0, 470.0)],
+            Self::S460NH(Hollow::ColdFormed) => &[(40.0, 460.0, 550.0)],
+            Self::S275MH => &[(40.0, 275.0, 360.0)],
+            Self::S355MH => &[(40.0, 355.0, 470.0)],
+            Self::S420MH => &[(40.0, 420.0, 500.0)],
+            Self::S460MH => &[(40.0, 460.0, 530.0)],
+        }
+    }
+
+    /// Whether the grade is S460, which has its own column in the buckling curves of
+    /// NS-EN 1993-1-1 Table 6.2
+    #[must_use]
+    pub fn is_s460(&self) -> bool {
+        self.table()[0].1 >= 460.0
+    }
+
+    /// Nominal (`f_y`, `f_u`) in N/mm^2 for an element of thickness `t` in mm
+    #[must_use]
+    pub fn strengths(&self, t: f64) -> Option<(f64, f64)> {
+        self.table()
+            .iter()
+            .find(|(t_max, _, _)| t <= *t_max)
+            .map(|(_, fy, fu)| (*fy, *fu))
     }
 }
 
 #[allow(non_snake_case)]
+/// Deserialising checks that the properties are within their physical ranges
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Steel {
     fy: f64,
     fu: f64,
@@ -38,6 +174,11 @@ pub struct Steel {
     density: f64,
     gamma_m0: f64,
     gamma_m1: f64,
+    gamma_m2: f64,
+    gamma_m_fi: f64,
+    /// Grade S460, kept apart from the yield strength which is reduced for thick elements
+    #[serde(default)]
+    s460: bool,
 }
 
 impl Steel {
@@ -57,65 +198,138 @@ impl Steel {
             density,
             gamma_m0,
             gamma_m1,
+            gamma_m2: NationalAnnex::NS.gamma_m2(),
+            gamma_m_fi: NationalAnnex::NS.gamma_m_fi(),
+            s460: fy >= 460.0,
         }
     }
 
+    /// Whether the steel is grade S460, choosing its column of Table 6.2
+    #[must_use]
+    pub const fn is_s460(&self) -> bool {
+        self.s460
+    }
+
+    /// Takes the partial factors from the given National Annex
+    #[must_use]
+    pub const fn with_annex(mut self, annex: &NationalAnnex) -> Self {
+        self.gamma_m0 = annex.gamma_m0();
+        self.gamma_m1 = annex.gamma_m1();
+        self.gamma_m2 = annex.gamma_m2();
+        self.gamma_m_fi = annex.gamma_m_fi();
+        self
+    }
+
+    /// Steel with the strengths for elements up to 40 mm thick
     #[must_use]
     pub fn from(class: &Variant) -> Self {
-        match class {
-            Variant::S235 => Self {
-                fy: 235.0,
-                fu: 360.0,
-                ..Default::default()
-            },
-            Variant::S275 => Self {
-                fy: 275.0,
-                fu: 430.0,
-                ..Default::default()
-            },
-            Variant::S355 => Self {
-                fy: 355.0,
-                fu: 490.0,
-                ..Default::default()
-            },
-            Variant::S450 => Self {
-                fy: 440.0,
-                fu: 550.0,
-                ..Default::default()
-            },
+        let (_, fy, fu) = class.table()[0];
+        Self {
+            fy,
+            fu,
+            s460: class.is_s460(),
+            ..Default::default()
         }
     }
+
+    /// Steel with the strengths for an element of thickness `t` in [mm]
+    ///
+    /// # Errors
+    /// Will return an error if `t` is thicker than NS-EN 1993-1-1 Table 3.1 covers for the grade
+    pub fn from_thickness(class: &Variant, t: f64) -> Result<Self, EmsError> {
+        let (fy, fu) = class.strengths(t).ok_or_else(|| {
+            EmsError::out_of_range_error(
+                format!("{} is not tabulated for thickness {t} mm", class.name()),
+                None,
+            )
+        })?;
+        Ok(Self {
+            fy,
+            fu,
+            s460: class.is_s460(),
+            ..Default::default()
+        })
+    }
+
+    /// Steel with the strengths governed by the thickest element of the cross-section
+    ///
+    /// # Errors
+    /// Will return an error if the governing thickness is not tabulated for the grade
+    pub fn for_crs(class: &Variant, crs: &dyn CrossSection) -> Result<Self, EmsError> {
+        Self::from_thickness(class, crs.t_max())
+    }
 }
 
+/// S355 up to 40 mm thick
 impl Default for Steel {
     fn default() -> Self {
-        Self::new(355.0, 490.0, 210_000.0, 7850.0, 1.05, 1.05)
+        let (_, fy, fu) = Variant::S355.table()[0];
+        Self::new(fy, fu, 210_000.0, 7850.0, 1.05, 1.05)
+    }
+}
+
+impl Serialize for Steel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Steel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let steel = Self::deserialize(deserializer)?;
+        steel.validate().map_err(de::Error::custom)?;
+        Ok(steel)
     }
 }
 
 impl Material for Steel {
+    fn definition(&self) -> MatDef {
+        MatDef::Steel(self.clone())
+    }
+    /// Also requires the tensile strength to be at least the yield strength
+    fn validate(&self) -> Result<(), EmsError> {
+        check_range("f_u", self.fu, self.fy, 2_500.0)?;
+        check_physical(self)
+    }
     fn E(&self) -> f64 {
         self.youngs_modulus
     }
     fn rho(&self) -> f64 {
         self.density
     }
+    /// NS-EN 1993-1-1 3.2.6(1)
+    fn nu(&self) -> f64 {
+        0.3
+    }
+    /// NS-EN 1993-1-1 3.2.6(1), for temperatures up to 100 degrees Celsius
+    fn alpha_t(&self) -> f64 {
+        12e-6
+    }
     fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
         self.fy / self.gamma_m0(limit_state_type)
     }
     fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
-        self.fu / self.gamma_m0(limit_state_type)
+        self.fu / self.gamma_m2(limit_state_type)
     }
     fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
         match limit_state_type {
             LimitStateType::K => 1.00,
             LimitStateType::D => self.gamma_m0,
+            LimitStateType::A => self.gamma_m_fi,
         }
     }
     fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
         match limit_state_type {
             LimitStateType::K => 1.00,
             LimitStateType::D => self.gamma_m1,
+            LimitStateType::A => self.gamma_m_fi,
+        }
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K => 1.00,
+            LimitStateType::D => self.gamma_m2,
+            LimitStateType::A => self.gamma_m_fi,
         }
     }
 }
@@ -123,8 +337,18 @@ impl Material for Steel {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crs::{rect::Rect, standard::PresetCrs};
     use crate::zequality::Zeq;
 
+    #[test]
+    fn deserialising_validates_ranges() {
+        let text = serde_json::to_string(&Steel::default()).expect("Couldnt serialise");
+        let back: Steel = serde_json::from_str(&text).expect(&text);
+        assert_zeq!(back.f_u(&LimitStateType::K), 510.0);
+        let swapped = text.replace("\"fu\":510.0", "\"fu\":300.0");
+        assert!(serde_json::from_str::<Steel>(&swapped).is_err());
+        assert!(ron::from_str::<Steel>(&ron::to_string(&back).expect(&text)).is_ok());
+    }
     #[test]
     fn correct_gamma() {
         let steel = Steel::default();
@@ -132,9 +356,117 @@ mod tests {
         assert_zeq!(steel.gamma_m0(&LimitStateType::D), 1.05);
     }
     #[test]
+    fn elastic_constants() {
+        let steel = Steel::default();
+        assert_zeq!(steel.G(), 80_769.230_769);
+        assert_zeq!(steel.json()["alpha_T"].as_f64().unwrap_or_default(), 12e-6);
+    }
+    #[test]
     fn can_create_expected_steel_class() {
         let steel = Steel::from(&Variant::S355);
         assert_zeq!(steel.f_y(&LimitStateType::K), 355.0);
-        assert_zeq!(steel.f_u(&LimitStateType::K), 490.0);
+        assert_zeq!(steel.f_u(&LimitStateType::K), 510.0);
+    }
+    #[test]
+    fn strengths_follow_table_3_1() {
+        // (grade, f_y and f_u for t <= 40 mm, and for the thicker range where given)
+        let table = [
+            ("S235", (235.0, 360.0), Some((215.0, 360.0))),
+            ("S275", (275.0, 430.0), Some((255.0, 410.0))),
+            ("S355", (355.0, 510.0), Some((335.0, 470.0))),
+            ("S450", (440.0, 550.0), Some((410.0, 550.0))),
+            ("S275N", (275.0, 370.0), Some((255.0, 370.0))),
+            ("S355N", (355.0, 470.0), Some((335.0, 470.0))),
+            ("S420N", (420.0, 520.0), Some((390.0, 520.0))),
+            ("S460N", (460.0, 540.0), Some((430.0, 540.0))),
+            ("S275M", (275.0, 370.0), Some((255.0, 360.0))),
+            ("S355M", (355.0, 470.0), Some((335.0, 450.0))),
+            ("S420M", (420.0, 520.0), Some((390.0, 500.0))),
+            ("S460M", (460.0, 540.0), Some((430.0, 530.0))),
+            ("S235H", (235.0, 360.0), Some((215.0, 340.0))),
+            ("S275H", (275.0, 430.0), Some((255.0, 410.0))),
+            ("S355H", (355.0, 510.0), Some((335.0, 490.0))),
+            ("S275NH", (275.0, 370.0), Some((255.0, 370.0))),
+            ("S355NH", (355.0, 470.0), Some((335.0, 470.0))),
+            ("S460NH", (460.0, 560.0), Some((430.0, 550.0))),
+            ("S420NH", (420.0, 540.0), Some((390.0, 520.0))),
+            ("S235H (EN 10219)", (235.0, 360.0), None),
+            ("S275H (EN 10219)", (275.0, 430.0), None),
+            ("S355H (EN 10219)", (355.0, 510.0), None),
+            ("S275NH (EN 10219)", (275.0, 370.0), None),
+            ("S355NH (EN 10219)", (355.0, 470.0), None),
+            ("S460NH (EN 10219)", (460.0, 550.0), None),
+            ("S275MH", (275.0, 360.0), None),
+            ("S355MH", (355.0, 470.0), None),
+            ("S420MH", (420.0, 500.0), None),
+            ("S460MH", (460.0, 530.0), None),
+        ];
+        for (name, thin, thick) in table {
+            let variant = Variant::get(name).expect(name);
+            assert_eq!(variant.strengths(40.0), Some(thin), "{name}");
+            assert_eq!(variant.strengths(50.0), thick, "{name}");
+        }
+    }
+    #[test]
+    fn partial_factors_follow_annex() {
+        let steel = Steel::default().with_annex(&NationalAnnex::Recommended);
+        assert_zeq!(steel.gamma_m0(&LimitStateType::D), 1.00);
+        assert_zeq!(steel.f_y(&LimitStateType::D), 355.0);
+        assert_zeq!(steel.f_u(&LimitStateType::D), 510.0 / 1.25);
+
+        let steel = Steel::default().with_annex(&NationalAnnex::DS);
+        assert_zeq!(steel.gamma_m1(&LimitStateType::D), 1.20);
+        assert_zeq!(steel.gamma_m2(&LimitStateType::D), 1.35);
+    }
+    #[test]
+    fn strength_is_reduced_for_thick_elements() {
+        let steel = Steel::from_thickness(&Variant::S355, 40.0).expect("Couldnt create steel");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 355.0);
+        let steel = Steel::from_thickness(&Variant::S355, 50.0).expect("Couldnt create steel");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 335.0);
+        assert_zeq!(steel.f_u(&LimitStateType::K), 470.0);
+        assert!(!steel.is_s460());
+        let steel = Steel::from_thickness(&Variant::S460M, 50.0).expect("Couldnt create steel");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 430.0);
+        assert!(steel.is_s460());
+
+        assert!(Steel::from_thickness(&Variant::S355, 90.0).is_err());
+        assert!(Steel::from_thickness(&Variant::S355H(Hollow::HotFinished), 70.0).is_err());
+        assert!(Steel::from_thickness(&Variant::S355H(Hollow::ColdFormed), 45.0).is_err());
+    }
+    #[test]
+    fn strength_follows_governing_thickness_of_section() {
+        // HEB 1000 has 36 mm flanges, while a 70x90 mm flat bar is governed by its 70 mm side
+        let heb = PresetCrs::from_name("HEB 1000").expect("Couldnt create crs");
+        let steel = Steel::for_crs(&Variant::S355, &heb).expect("Couldnt create steel");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 355.0);
+
+        let bar = Rect::new(70.0, 90.0);
+        let steel = Steel::for_crs(&Variant::S275, &bar).expect("Couldnt create steel");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 255.0);
+    }
+    #[test]
+    fn hollow_grades_depend_on_product_standard() {
+        // EN 10219 only tabulates walls up to 40 mm
+        let hot = Steel::from_thickness(&Variant::S355NH(Hollow::HotFinished), 50.0)
+            .expect("Couldnt create steel");
+        assert_zeq!(hot.f_y(&LimitStateType::K), 335.0);
+        assert!(Steel::from_thickness(&Variant::S355NH(Hollow::ColdFormed), 50.0).is_err());
+    }
+    #[test]
+    fn grades_can_be_found_by_name() {
+        for name in Variant::variants() {
+            let variant = Variant::get(&name).expect("Couldnt find variant");
+            assert_eq!(variant.name(), name);
+        }
+        assert_eq!(
+            Variant::get("S355NH (EN 10219)"),
+            Some(Variant::S355NH(Hollow::ColdFormed))
+        );
+        assert_eq!(
+            Variant::get("S355NH"),
+            Some(Variant::S355NH(Hollow::HotFinished))
+        );
+        assert_eq!(Variant::get("S450"), Some(Variant::S450));
     }
 }
@@ -0,0 +1,284 @@
+use crate::erc::NSEN_1993_1_2::EPS_U;
+use crate::LimitStateType;
+
+use super::{
+    concrete::Concrete, heated::Heated, reinforcement::Reinforcement, stainless::Stainless,
+    Material,
+};
+
+/// Uniaxial stress-strain relationship for nonlinear section analysis, with both strains and
+/// stresses positive in tension
+pub trait StressStrain {
+    /// Stress in N/mm^2 at the strain `strain`
+    fn stress(&self, strain: f64) -> f64;
+    /// Largest absolute strain the relationship is valid for, by default the limiting principal
+    /// strain of NS-EN 1993-1-5 C.8(1) Note 2
+    fn eps_u(&self) -> f64 {
+        0.05
+    }
+}
+
+/// Elastic-perfectly plastic, NS-EN 1993-1-5 C.6(2) Figure C.2 a)
+#[derive(Clone, Debug)]
+pub struct ElasticPlastic {
+    pub e: f64,
+    pub f_y: f64,
+}
+
+impl ElasticPlastic {
+    #[must_use]
+    pub const fn new(e: f64, f_y: f64) -> Self {
+        Self { e, f_y }
+    }
+    #[must_use]
+    pub fn from_material(mat: &dyn Material, limit_state_type: &LimitStateType) -> Self {
+        Self::new(mat.E(), mat.f_y(limit_state_type))
+    }
+}
+
+impl StressStrain for ElasticPlastic {
+    fn stress(&self, strain: f64) -> f64 {
+        (self.e * strain).clamp(-self.f_y, self.f_y)
+    }
+}
+
+/// Bilinear with the hardening modulus `e_t` after yielding, NS-EN 1993-1-5 C.6(2)
+/// Figure C.2 b)
+#[derive(Clone, Debug)]
+pub struct Bilinear {
+    pub e: f64,
+    pub f_y: f64,
+    pub e_t: f64,
+}
+
+impl Bilinear {
+    #[must_use]
+    pub const fn new(e: f64, f_y: f64, e_t: f64) -> Self {
+        Self { e, f_y, e_t }
+    }
+    /// With the nominal plateau slope E/10000
+    #[must_use]
+    pub fn from_material(mat: &dyn Material, limit_state_type: &LimitStateType) -> Self {
+        Self::new(mat.E(), mat.f_y(limit_state_type), mat.E() / 10_000.0)
+    }
+}
+
+impl StressStrain for Bilinear {
+    fn stress(&self, strain: f64) -> f64 {
+        let eps_y = self.f_y / self.e;
+        if strain.abs() <= eps_y {
+            return self.e * strain;
+        }
+        strain.signum() * self.e_t.mul_add(strain.abs() - eps_y, self.f_y)
+    }
+}
+
+/// Quad-linear model of hot-rolled carbon steel by Yun and Gardner (2017), refining
+/// NS-EN 1993-1-5 C.6(2) Figure C.2 c)
+///
+/// A yield plateau up to `eps_sh` is followed by strain hardening in two branches meeting at
+/// `C_1 eps_u`, reaching `f_u` at `eps_u` beyond which the stress stays at `f_u`
+#[derive(Clone, Debug)]
+pub struct QuadLinear {
+    pub e: f64,
+    pub f_y: f64,
+    pub f_u: f64,
+    pub eps_sh: f64,
+    pub eps_u: f64,
+}
+
+impl QuadLinear {
+    #[must_use]
+    pub const fn new(e: f64, f_y: f64, f_u: f64, eps_sh: f64, eps_u: f64) -> Self {
+        Self {
+            e,
+            f_y,
+            f_u,
+            eps_sh,
+            eps_u,
+        }
+    }
+    /// With the strains at the onset of hardening and at `f_u` predicted from the ratio
+    /// `f_y`/`f_u` of hot-rolled carbon steel, as proposed by Yun and Gardner (2017)
+    ///
+    /// The ratio is taken from the characteristic strengths, as the partial factors on `f_y`
+    /// and `f_u` differ, and only the stresses are reduced for `limit_state_type`
+    #[must_use]
+    pub fn from_material(mat: &dyn Material, limit_state_type: &LimitStateType) -> Self {
+        let (f_y, f_u) = (mat.f_y(limit_state_type), mat.f_u(limit_state_type));
+        let ratio = mat.f_y(&LimitStateType::K) / mat.f_u(&LimitStateType::K);
+        let eps_sh = 0.1f64.mul_add(ratio, -0.055).clamp(0.015, 0.03);
+        let eps_u = (0.6 * (1.0 - ratio)).max(0.06);
+        Self::new(mat.E(), f_y, f_u.max(f_y), eps_sh, eps_u)
+    }
+    /// Strains `C_1 eps_u`, where the hardening branches meet, and `C_2 eps_u`, which sets the
+    /// slope of the first branch
+    fn c_strains(&self) -> (f64, f64) {
+        let hardening = self.eps_u - self.eps_sh;
+        (
+            0.25f64.mul_add(hardening, self.eps_sh),
+            0.4f64.mul_add(hardening, self.eps_sh),
+        )
+    }
+}
+
+impl StressStrain for QuadLinear {
+    fn stress(&self, strain: f64) -> f64 {
+        let eps = strain.abs();
+        let stress = if eps <= self.f_y / self.e {
+            self.e * eps
+        } else if eps <= self.eps_sh {
+            self.f_y
+        } else if eps < self.eps_u {
+            let (eps_1, eps_2) = self.c_strains();
+            let e_sh = (self.f_u - self.f_y) / (eps_2 - self.eps_sh);
+            let f_1 = e_sh.mul_add(eps_1 - self.eps_sh, self.f_y);
+            if eps <= eps_1 {
+                e_sh.mul_add(eps - self.eps_sh, self.f_y)
+            } else {
+                ((self.f_u - f_1) / (self.eps_u - eps_1)).mul_add(eps - eps_1, f_1)
+            }
+        } else {
+            self.f_u
+        };
+        strain.signum() * stress
+    }
+}
+
+/// Ramberg-Osgood relationship with the 0.2% proof strength `f_y` and exponent `n`,
+/// NS-EN 1993-1-4 C.1
+#[derive(Clone, Debug)]
+pub struct RambergOsgood {
+    pub e: f64,
+    pub f_y: f64,
+    pub n: f64,
+}
+
+impl RambergOsgood {
+    #[must_use]
+    pub const fn new(e: f64, f_y: f64, n: f64) -> Self {
+        Self { e, f_y, n }
+    }
+    /// Strain at the stress `sigma`
+    #[must_use]
+    pub fn strain(&self, sigma: f64) -> f64 {
+        sigma.signum()
+            * (sigma.abs() / self.f_y)
+                .powf(self.n)
+                .mul_add(0.002, sigma.abs() / self.e)
+    }
+}
+
+impl StressStrain for RambergOsgood {
+    /// Found by bisection, as C.1 gives the strain explicitly
+    #[allow(clippy::while_float)]
+    fn stress(&self, strain: f64) -> f64 {
+        let mut high = self.f_y;
+        while self.strain(high) < strain.abs() {
+            high *= 2.0;
+        }
+        let mut low = 0.0;
+        for _ in 0..100 {
+            let mid = 0.5 * (low + high);
+            if self.strain(mid) < strain.abs() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        strain.signum() * 0.5 * (low + high)
+    }
+}
+
+/// Two-stage Ramberg-Osgood relationship of NS-EN 1993-1-4 Annex C
+impl StressStrain for Stainless {
+    fn stress(&self, strain: f64) -> f64 {
+        Self::stress(self, strain)
+    }
+}
+
+/// Parabola-rectangle diagram without tensile strength, NS-EN 1992-1-1 3.1.7(1)
+impl StressStrain for Concrete {
+    fn stress(&self, strain: f64) -> f64 {
+        -Self::stress(self, -strain)
+    }
+    fn eps_u(&self) -> f64 {
+        self.eps_cu2()
+    }
+}
+
+impl StressStrain for Reinforcement {
+    fn stress(&self, strain: f64) -> f64 {
+        Self::stress(self, strain)
+    }
+    fn eps_u(&self) -> f64 {
+        self.eps_ud()
+    }
+}
+
+/// Characteristic relationship of carbon steel at elevated temperature, NS-EN 1993-1-2 3.2.2
+impl StressStrain for Heated {
+    fn stress(&self, strain: f64) -> f64 {
+        strain.signum() * Self::stress(self, strain)
+    }
+    fn eps_u(&self) -> f64 {
+        EPS_U
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mat::steel::Steel;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn elastic_plastic_is_symmetric() {
+        let epp = ElasticPlastic::from_material(&Steel::default(), &LimitStateType::K);
+        assert_zeq!(epp.stress(0.001), 210.0);
+        assert_zeq!(epp.stress(0.01), 355.0);
+        assert_zeq!(epp.stress(-0.01), -355.0);
+    }
+
+    #[test]
+    fn bilinear_hardens_after_yield() {
+        let curve = Bilinear::new(210_000.0, 355.0, 2100.0);
+        assert_zeq!(curve.stress(-0.001), -210.0);
+        assert_zeq!(curve.stress(0.012), 376.65);
+    }
+
+    #[test]
+    fn quad_linear_reaches_ultimate_strength() {
+        let curve = QuadLinear::from_material(&Steel::default(), &LimitStateType::K);
+        // The ratio 355/510 puts eps_sh at its lower limit
+        assert_zeq!(curve.eps_sh, 0.015);
+        assert_zeq!(curve.eps_u, 0.182_353);
+        assert_zeq!(curve.stress(0.01), 355.0);
+        // First and second hardening branches, meeting at C_1 eps_u
+        assert_zeq!(curve.stress(0.03), 389.731_986);
+        assert_zeq!(curve.stress(0.056_838_235), 451.875);
+        assert_zeq!(curve.stress(0.09), 467.231_986);
+        assert_zeq!(curve.stress(-0.2), -510.0);
+        // Partial factors scale the stresses without changing the strains
+        let design = QuadLinear::from_material(&Steel::default(), &LimitStateType::D);
+        assert_zeq!(design.eps_u, curve.eps_u);
+        assert_zeq!(design.stress(-0.2), -408.0);
+    }
+
+    #[test]
+    fn ramberg_osgood_inverts_strain() {
+        let curve = RambergOsgood::new(200_000.0, 230.0, 6.0);
+        assert_zeq!(curve.strain(230.0), 0.003_15);
+        assert_zeq!(curve.stress(0.003_15), 230.0);
+        assert_zeq!(curve.stress(-0.003_15), -230.0);
+    }
+
+    #[test]
+    fn materials_follow_sign_convention() {
+        let concrete = Concrete::default();
+        assert_zeq!(StressStrain::stress(&concrete, 0.001), 0.0);
+        assert_zeq!(StressStrain::stress(&concrete, -0.003), -17.0);
+        let hot = Heated::new(Steel::default(), 600.0);
+        assert_zeq!(StressStrain::stress(&hot, -0.05), -166.85);
+    }
+}
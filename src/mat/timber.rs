@@ -0,0 +1,322 @@
+use crate::erc::NSEN_1995::{f_3_1, f_3_2, k_mod, LoadDuration, ServiceClass};
+use crate::LimitStateType;
+use serde::{Deserialize, Serialize};
+
+use super::{MatDef, Material};
+
+/// Strength classes of solid timber, NS-EN 338, and glued laminated timber, NS-EN 14080
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    C14,
+    C16,
+    C18,
+    C20,
+    C22,
+    C24,
+    C27,
+    C30,
+    C35,
+    C40,
+    GL24h,
+    GL28h,
+    GL30h,
+    GL32h,
+}
+
+/// Characteristic values of a strength class in N/mm^2 and kg/m^3
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Properties {
+    pub f_m_k: f64,
+    pub f_t_0_k: f64,
+    pub f_c_0_k: f64,
+    pub f_v_k: f64,
+    pub e_0_mean: f64,
+    pub e_0_05: f64,
+    pub rho_k: f64,
+    pub rho_mean: f64,
+    pub g_mean: f64,
+}
+
+impl Variant {
+    const ALL: [Self; 14] = [
+        Self::C14,
+        Self::C16,
+        Self::C18,
+        Self::C20,
+        Self::C22,
+        Self::C24,
+        Self::C27,
+        Self::C30,
+        Self::C35,
+        Self::C40,
+        Self::GL24h,
+        Self::GL28h,
+        Self::GL30h,
+        Self::GL32h,
+    ];
+
+    #[must_use]
+    pub fn get(identifier: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|variant| variant.name().eq_ignore_ascii_case(identifier))
+    }
+    #[must_use]
+    pub fn variants() -> Vec<String> {
+        Self::ALL
+            .iter()
+            .map(|variant| variant.name().to_owned())
+            .collect()
+    }
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::C14 => "C14",
+            Self::C16 => "C16",
+            Self::C18 => "C18",
+            Self::C20 => "C20",
+            Self::C22 => "C22",
+            Self::C24 => "C24",
+            Self::C27 => "C27",
+            Self::C30 => "C30",
+            Self::C35 => "C35",
+            Self::C40 => "C40",
+            Self::GL24h => "GL24h",
+            Self::GL28h => "GL28h",
+            Self::GL30h => "GL30h",
+            Self::GL32h => "GL32h",
+        }
+    }
+    #[must_use]
+    pub const fn glulam(&self) -> bool {
+        matches!(self, Self::GL24h | Self::GL28h | Self::GL30h | Self::GL32h)
+    }
+
+    #[must_use]
+    pub const fn properties(&self) -> Properties {
+        // (f_m, f_t_0, f_c_0, f_v, E_0_mean, E_0_05, rho_k, rho_mean, G_mean)
+        let row = match self {
+            Self::C14 => (14.0, 7.2, 16.0, 3.0, 7000.0, 4700.0, 290.0, 350.0, 440.0),
+            Self::C16 => (16.0, 8.5, 17.0, 3.2, 8000.0, 5400.0, 310.0, 370.0, 500.0),
+            Self::C18 => (18.0, 10.0, 18.0, 3.4, 9000.0, 6000.0, 320.0, 380.0, 560.0),
+            Self::C20 => (20.0, 11.5, 19.0, 3.6, 9500.0, 6400.0, 330.0, 400.0, 590.0),
+            Self::C22 => (22.0, 13.0, 20.0, 3.8, 10000.0, 6700.0, 340.0, 410.0, 630.0),
+            Self::C24 => (24.0, 14.5, 21.0, 4.0, 11000.0, 7400.0, 350.0, 420.0, 690.0),
+            Self::C27 => (27.0, 16.5, 22.0, 4.0, 11500.0, 7700.0, 360.0, 430.0, 720.0),
+            Self::C30 => (30.0, 19.0, 24.0, 4.0, 12000.0, 8000.0, 380.0, 460.0, 750.0),
+            Self::C35 => (35.0, 22.5, 25.0, 4.0, 13000.0, 8700.0, 390.0, 470.0, 810.0),
+            Self::C40 => (40.0, 26.0, 27.0, 4.0, 14000.0, 9400.0, 400.0, 480.0, 880.0),
+            Self::GL24h => (24.0, 19.2, 24.0, 3.5, 11500.0, 9600.0, 385.0, 420.0, 650.0),
+            Self::GL28h => (28.0, 22.3, 28.0, 3.5, 12600.0, 10500.0, 425.0, 460.0, 650.0),
+            Self::GL30h => (30.0, 24.0, 30.0, 3.5, 13600.0, 11300.0, 430.0, 480.0, 650.0),
+            Self::GL32h => (32.0, 25.6, 32.0, 3.5, 14200.0, 11800.0, 440.0, 490.0, 650.0),
+        };
+        Properties {
+            f_m_k: row.0,
+            f_t_0_k: row.1,
+            f_c_0_k: row.2,
+            f_v_k: row.3,
+            e_0_mean: row.4,
+            e_0_05: row.5,
+            rho_k: row.6,
+            rho_mean: row.7,
+            g_mean: row.8,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Timber {
+    f_m_k: f64,
+    f_t_0_k: f64,
+    f_c_0_k: f64,
+    f_v_k: f64,
+    e_0_mean: f64,
+    e_0_05: f64,
+    g_mean: f64,
+    density: f64,
+    glulam: bool,
+    gamma_m: f64,
+    pub service_class: ServiceClass,
+    pub load_duration: LoadDuration,
+}
+
+impl Timber {
+    /// Partial factor from the Norwegian National Annex, Table NA.2.3
+    #[must_use]
+    pub const fn from(class: &Variant) -> Self {
+        let p = class.properties();
+        Self {
+            f_m_k: p.f_m_k,
+            f_t_0_k: p.f_t_0_k,
+            f_c_0_k: p.f_c_0_k,
+            f_v_k: p.f_v_k,
+            e_0_mean: p.e_0_mean,
+            e_0_05: p.e_0_05,
+            g_mean: p.g_mean,
+            density: p.rho_mean,
+            glulam: class.glulam(),
+            gamma_m: if class.glulam() { 1.15 } else { 1.25 },
+            service_class: ServiceClass::One,
+            load_duration: LoadDuration::MediumTerm,
+        }
+    }
+    #[must_use]
+    pub const fn with_service_class(mut self, service_class: ServiceClass) -> Self {
+        self.service_class = service_class;
+        self
+    }
+    #[must_use]
+    pub const fn with_load_duration(mut self, load_duration: LoadDuration) -> Self {
+        self.load_duration = load_duration;
+        self
+    }
+
+    #[must_use]
+    pub const fn glulam(&self) -> bool {
+        self.glulam
+    }
+    #[must_use]
+    pub const fn k_mod(&self) -> f64 {
+        k_mod(&self.service_class, &self.load_duration)
+    }
+    /// Straightness factor for the instability factor, 6.3.2(3)
+    #[must_use]
+    pub const fn beta_c(&self) -> f64 {
+        if self.glulam {
+            0.1
+        } else {
+            0.2
+        }
+    }
+    /// Size factor for a bending depth or tension width `h` in mm, 3.2(3) and 3.3(3)
+    #[must_use]
+    pub fn k_h(&self, h: f64) -> f64 {
+        if self.glulam {
+            f_3_2(h)
+        } else {
+            f_3_1(h)
+        }
+    }
+    /// Fifth percentile modulus of elasticity parallel to grain
+    #[must_use]
+    pub const fn e_0_05(&self) -> f64 {
+        self.e_0_05
+    }
+
+    /// Design value `k_mod` `f_k` / `gamma_m` of a characteristic strength, 2.4.1
+    fn design(&self, f_k: f64, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            LimitStateType::K => f_k,
+            LimitStateType::D | LimitStateType::A => {
+                self.k_mod() * f_k / self.gamma_m0(limit_state_type)
+            }
+        }
+    }
+    #[must_use]
+    pub fn f_m(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.design(self.f_m_k, limit_state_type)
+    }
+    #[must_use]
+    pub fn f_t_0(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.design(self.f_t_0_k, limit_state_type)
+    }
+    #[must_use]
+    pub fn f_c_0(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.design(self.f_c_0_k, limit_state_type)
+    }
+    #[must_use]
+    pub fn f_v(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.design(self.f_v_k, limit_state_type)
+    }
+}
+
+impl Default for Timber {
+    fn default() -> Self {
+        Self::from(&Variant::C24)
+    }
+}
+
+impl Material for Timber {
+    fn definition(&self) -> MatDef {
+        MatDef::Timber(self.clone())
+    }
+    /// Mean modulus of elasticity parallel to grain
+    fn E(&self) -> f64 {
+        self.e_0_mean
+    }
+    fn rho(&self) -> f64 {
+        self.density
+    }
+    /// Mean shear modulus, as timber is orthotropic
+    fn G(&self) -> f64 {
+        self.g_mean
+    }
+    /// Poisson's ratio between the longitudinal and radial directions, which NS-EN 1995-1-1
+    /// does not give
+    fn nu(&self) -> f64 {
+        0.4
+    }
+    /// Parallel to grain
+    fn alpha_t(&self) -> f64 {
+        5e-6
+    }
+    /// Bending strength, as timber has no yield point
+    fn f_y(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.f_m(limit_state_type)
+    }
+    /// Tensile strength parallel to grain
+    fn f_u(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.f_t_0(limit_state_type)
+    }
+    fn gamma_m0(&self, limit_state_type: &LimitStateType) -> f64 {
+        match limit_state_type {
+            // NS-EN 1995-1-1 Table NA.2.3 for accidental combinations
+            LimitStateType::K | LimitStateType::A => 1.00,
+            LimitStateType::D => self.gamma_m,
+        }
+    }
+    fn gamma_m1(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m0(limit_state_type)
+    }
+    fn gamma_m2(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.gamma_m0(limit_state_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn design_strength_uses_k_mod() {
+        let timber = Timber::default();
+        assert_zeq!(timber.f_m(&LimitStateType::K), 24.0);
+        assert_zeq!(timber.f_m(&LimitStateType::D), 15.36);
+        let timber = timber.with_load_duration(LoadDuration::Permanent);
+        assert_zeq!(timber.f_m(&LimitStateType::D), 11.52);
+    }
+
+    #[test]
+    fn glulam_has_lower_partial_factor() {
+        let timber = Timber::from(&Variant::GL30h);
+        assert!(timber.glulam());
+        assert_zeq!(timber.gamma_m0(&LimitStateType::D), 1.15);
+        assert_zeq!(timber.beta_c(), 0.1);
+    }
+
+    #[test]
+    fn shear_modulus_is_tabulated() {
+        assert_zeq!(Timber::default().G(), 690.0);
+        assert_zeq!(Timber::from(&Variant::GL30h).G(), 650.0);
+    }
+
+    #[test]
+    fn classes_can_be_found_by_name() {
+        assert_eq!(Variant::get("gl28h"), Some(Variant::GL28h));
+        for name in Variant::variants() {
+            assert!(Variant::get(&name).is_some());
+        }
+    }
+}
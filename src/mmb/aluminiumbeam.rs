@@ -0,0 +1,173 @@
+use crate::crs::CrossSection;
+use crate::erc::NSEN_1999::{kappa, khi, M_o_Rd, M_u_Rd, N_b_Rd, N_o_Rd, N_u_Rd};
+use crate::mat::{aluminium::Aluminium, Material};
+use crate::{crs::rect::Rect, Axis, LimitStateType};
+use serde::{Deserialize, Serialize};
+
+/// Aluminium member checked according to NS-EN 1999-1-1 section 6
+#[derive(Serialize, Deserialize)]
+pub struct AluminiumBeam {
+    pub crs: Box<dyn CrossSection>,
+    pub mat: Aluminium,
+    /// Part of the cross-section area inside the heat affected zone of longitudinal welds
+    pub haz_area: f64,
+    /// Class 1 or 2 cross-section, allowing the plastic shape factor in bending
+    pub compact: bool,
+}
+
+impl Default for AluminiumBeam {
+    fn default() -> Self {
+        Self {
+            crs: Box::new(Rect::default()),
+            mat: Aluminium::default(),
+            haz_area: 0.0,
+            compact: true,
+        }
+    }
+}
+
+impl AluminiumBeam {
+    #[must_use]
+    pub fn new(crs: Box<dyn CrossSection>, mat: Aluminium) -> Self {
+        Self {
+            crs,
+            mat,
+            ..Default::default()
+        }
+    }
+    /// Member with longitudinal welds, where `haz_area` is the area softened by the welding
+    #[must_use]
+    pub const fn with_haz(mut self, haz_area: f64) -> Self {
+        self.haz_area = haz_area;
+        self
+    }
+
+    fn haz_ratio(&self) -> f64 {
+        (self.haz_area / self.crs.area()).clamp(0.0, 1.0)
+    }
+    /// Area with the heat affected zone reduced by `rho`
+    fn reduced_area(&self, rho: f64) -> f64 {
+        self.haz_area.mul_add(-(1.0 - rho), self.crs.area())
+    }
+
+    /// Design tension resistance, the lesser of yielding and net section fracture, 6.2.3
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_t(&self, limit_state_type: &LimitStateType) -> f64 {
+        let n_o = N_o_Rd(
+            self.crs.area(),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m1(limit_state_type),
+        );
+        let n_u = N_u_Rd(
+            self.reduced_area(self.mat.rho_u_haz()),
+            self.mat.f_u(&LimitStateType::K),
+            self.mat.gamma_m2(limit_state_type),
+        );
+        n_o.min(n_u)
+    }
+    /// Design compression resistance of the cross-section, 6.2.4
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_c(&self, limit_state_type: &LimitStateType) -> f64 {
+        N_o_Rd(
+            self.reduced_area(self.mat.rho_o_haz()),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m1(limit_state_type),
+        )
+    }
+    /// Design bending resistance, the lesser of yielding and net section fracture, 6.2.5
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_Rd(&self, axis: Axis, limit_state_type: &LimitStateType) -> f64 {
+        let alpha = if self.compact {
+            self.crs.w_pl(axis) / self.crs.w_el(axis)
+        } else {
+            1.0
+        };
+        let w_net =
+            self.crs.w_el(axis) * self.haz_ratio().mul_add(-(1.0 - self.mat.rho_u_haz()), 1.0);
+        let m_o = M_o_Rd(
+            alpha,
+            self.crs.w_el(axis),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m1(limit_state_type),
+        );
+        let m_u = M_u_Rd(
+            w_net,
+            self.mat.f_u(&LimitStateType::K),
+            self.mat.gamma_m2(limit_state_type),
+        );
+        m_o.min(m_u)
+    }
+
+    /// Flexural buckling resistance for the buckling length `lk`, 6.3.1
+    #[must_use]
+    pub fn buckle_cap(&self, lk: f64, axis: Axis, limit_state_type: &LimitStateType) -> f64 {
+        let class = self.mat.buckling_class();
+        let area = self.crs.area();
+        let fo = self.mat.f_y(&LimitStateType::K);
+        let lambda = (area * fo / self.euler_load(lk, axis)).sqrt();
+        N_b_Rd(
+            kappa(&class, lambda, self.haz_ratio(), self.mat.rho_o_haz()),
+            khi(&class, lambda),
+            area,
+            fo,
+            self.mat.gamma_m1(limit_state_type),
+        )
+    }
+
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn EI(&self, axis: Axis) -> f64 {
+        self.crs.I(axis) * self.mat.E()
+    }
+
+    #[must_use]
+    pub fn euler_load(&self, lk: f64, axis: Axis) -> f64 {
+        self.EI(axis) * (std::f64::consts::PI / lk).powi(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn tension_without_welds_yields() {
+        let mmb = AluminiumBeam::default();
+        assert_zeq!(mmb.N_t(&LimitStateType::K), 2_500_000.0);
+    }
+
+    #[test]
+    fn welds_reduce_tension_and_compression() {
+        let mmb = AluminiumBeam::default().with_haz(5000.0);
+        // 0.9 * (10000 - 5000 * 0.36) * 290
+        assert_zeq!(mmb.N_t(&LimitStateType::K), 2_140_200.0);
+        assert_zeq!(mmb.N_c(&LimitStateType::K), 1_875_000.0);
+    }
+
+    #[test]
+    fn compact_sections_use_shape_factor() {
+        let mut mmb = AluminiumBeam::default();
+        let m_pl = mmb.M_Rd(Axis::Y, &LimitStateType::K);
+        mmb.compact = false;
+        // alpha = 1.5 for a rectangle, but net section fracture governs at fu / fo
+        assert_zeq!(m_pl / mmb.M_Rd(Axis::Y, &LimitStateType::K), 1.16);
+    }
+
+    #[test]
+    fn buckling_reduces_capacity() {
+        let mmb = AluminiumBeam::default();
+        let short = mmb.buckle_cap(10.0, Axis::Y, &LimitStateType::K);
+        let long = mmb.buckle_cap(5000.0, Axis::Y, &LimitStateType::K);
+        assert_zeq!(short, 2_500_000.0);
+        assert!(long < short);
+        let welded = AluminiumBeam::default().with_haz(3000.0);
+        assert!(
+            welded.buckle_cap(2000.0, Axis::Y, &LimitStateType::K)
+                < mmb.buckle_cap(2000.0, Axis::Y, &LimitStateType::K)
+        );
+    }
+}
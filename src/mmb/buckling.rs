@@ -0,0 +1,175 @@
+use crate::Axis;
+use serde::{Deserialize, Serialize};
+
+/// Classical end conditions of an isolated member, with the theoretical effective length
+/// factors of the Euler cases
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EulerCase {
+    /// Pinned at both ends
+    Pinned,
+    /// Fixed at both ends
+    Fixed,
+    /// Fixed at one end and pinned at the other
+    FixedPinned,
+    /// Fixed at one end and free at the other
+    Cantilever,
+    /// Fixed at one end, with the other end free to sway but restrained against rotation
+    FixedGuided,
+}
+
+impl EulerCase {
+    /// Effective length factor, the buckling length over the member length
+    #[must_use]
+    pub const fn k(&self) -> f64 {
+        match self {
+            Self::Pinned | Self::FixedGuided => 1.0,
+            Self::Fixed => 0.5,
+            Self::FixedPinned => 0.7,
+            Self::Cantilever => 2.0,
+        }
+    }
+}
+
+/// Whether the frame is free to sway, so that the column ends may translate relative to each
+/// other
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Frame {
+    NonSway,
+    Sway,
+}
+
+/// Rotation at the far end of a beam restraining a column, setting the share of its stiffness
+/// `I / L` that acts on the column, NCCI SN008
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BeamEnd {
+    /// Far end fixed against rotation
+    Fixed,
+    /// Far end pinned
+    Pinned,
+    /// Far end rotating as much as the near end, bending the beam in double curvature
+    DoubleCurvature,
+    /// Far end rotating as much as the near end in the opposite direction, bending the beam
+    /// in single curvature
+    SingleCurvature,
+}
+
+impl BeamEnd {
+    /// Effective stiffness of a beam with the second moment of area `i` and span `length`,
+    /// without axial force in the beam
+    #[must_use]
+    pub fn stiffness(&self, i: f64, length: f64) -> f64 {
+        let factor = match self {
+            Self::Fixed => 1.0,
+            Self::Pinned => 0.75,
+            Self::DoubleCurvature => 1.5,
+            Self::SingleCurvature => 0.5,
+        };
+        factor * i / length
+    }
+}
+
+/// Distribution coefficient `eta` at a column end, NCCI SN008, zero for a fixed end and one
+/// for a pinned end
+///
+/// `k_c` is the stiffness `I / L` of the column, `k_adjacent` that of a column continuing past
+/// the joint and `k_beams` the sum of the effective stiffnesses of the beams
+#[must_use]
+pub fn distribution_factor(k_c: f64, k_adjacent: f64, k_beams: f64) -> f64 {
+    let k_columns = k_c + k_adjacent;
+    (k_columns / (k_columns + k_beams)).clamp(0.0, 1.0)
+}
+
+/// Effective length factor of a column in a frame from the distribution coefficients `eta_1`
+/// and `eta_2` at its ends, NCCI SN008, approximating the charts of Wood
+#[must_use]
+pub fn length_factor(eta_1: f64, eta_2: f64, frame: Frame) -> f64 {
+    let (sum, product) = (eta_1 + eta_2, eta_1 * eta_2);
+    match frame {
+        Frame::NonSway => {
+            0.265f64.mul_add(-product, 0.145f64.mul_add(sum, 1.0))
+                / 0.247f64.mul_add(-product, 0.364f64.mul_add(-sum, 2.0))
+        }
+        Frame::Sway => (0.12f64.mul_add(-product, 0.2f64.mul_add(-sum, 1.0))
+            / 0.6f64.mul_add(product, 0.8f64.mul_add(-sum, 1.0)))
+        .sqrt(),
+    }
+}
+
+/// Buckling lengths of a member about each axis, given as effective length factors of the
+/// member length
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BucklingLength {
+    /// Member length in [mm]
+    pub length: f64,
+    pub k_y: f64,
+    pub k_z: f64,
+}
+
+impl BucklingLength {
+    #[must_use]
+    pub const fn new(length: f64, k_y: f64, k_z: f64) -> Self {
+        Self { length, k_y, k_z }
+    }
+    /// Same end conditions about both axes
+    #[must_use]
+    pub const fn from_case(length: f64, case: EulerCase) -> Self {
+        Self::new(length, case.k(), case.k())
+    }
+    /// Column in a frame about each axis, with the distribution coefficients at its ends
+    /// about y and z
+    #[must_use]
+    pub fn from_frame(
+        length: f64,
+        eta_y: (f64, f64),
+        eta_z: (f64, f64),
+        frame_y: Frame,
+        frame_z: Frame,
+    ) -> Self {
+        Self::new(
+            length,
+            length_factor(eta_y.0, eta_y.1, frame_y),
+            length_factor(eta_z.0, eta_z.1, frame_z),
+        )
+    }
+    /// Buckling length `lk` in [mm] for flexural buckling about `axis`, where `Axis::X` gives
+    /// the member length for torsional buckling between fork supports
+    #[must_use]
+    pub fn lk(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.length,
+            Axis::Y => self.k_y * self.length,
+            Axis::Z => self.k_z * self.length,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zequality::Zeq;
+
+    #[test]
+    fn euler_cases() {
+        let lengths = BucklingLength::from_case(4000.0, EulerCase::Cantilever);
+        assert_zeq!(lengths.lk(Axis::Y), 8000.0);
+        assert_zeq!(lengths.lk(Axis::Z), 8000.0);
+        assert_zeq!(lengths.lk(Axis::X), 4000.0);
+        assert_zeq!(EulerCase::FixedPinned.k(), 0.7);
+    }
+    #[test]
+    fn frame_columns_span_the_euler_cases() {
+        // Fixed and pinned ends recover the Euler cases
+        assert_zeq!(length_factor(0.0, 0.0, Frame::NonSway), 0.5);
+        assert_zeq!(length_factor(1.0, 1.0, Frame::NonSway), 1.0);
+        assert_zeq!(length_factor(0.0, 0.0, Frame::Sway), 1.0);
+        assert_zeq!(length_factor(1.0, 0.0, Frame::Sway), 2.0);
+
+        let k_beams = 2.0 * BeamEnd::SingleCurvature.stiffness(2.0e8, 8000.0);
+        let eta = distribution_factor(1.0e8 / 4000.0, 0.0, k_beams);
+        assert_zeq!(eta, 0.5);
+        let lengths =
+            BucklingLength::from_frame(4000.0, (eta, 1.0), (1.0, 1.0), Frame::Sway, Frame::NonSway);
+        assert_zeq!(lengths.lk(Axis::Z), 4000.0);
+        assert!(lengths.lk(Axis::Y) > 8000.0);
+    }
+}
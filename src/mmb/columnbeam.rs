@@ -1,14 +1,92 @@
-use crate::crs::CrossSection;
-use crate::erc::NSEN_1993::{BuckleCurve, _compute_lamba, _compute_phi, f_6_47, f_6_49};
+use crate::crs::{CrossSection, Fabrication, Shape};
+use crate::erc::annex::NationalAnnex;
+use crate::erc::Check;
+use crate::erc::NSEN_1993::{
+    _compute_lamba, _compute_phi, annex_b_factors, buckling_curve, epsilon, f_6_13, f_6_18, f_6_22,
+    f_6_26, f_6_28, f_6_29, f_6_30, f_6_32, f_6_33, f_6_35, f_6_36, f_6_38, f_6_39, f_6_41,
+    f_6_41_exponents, f_6_47, f_6_49, f_6_55, f_6_56, f_6_57, f_6_58, f_6_6, f_6_61, f_6_62, f_6_7,
+    ltb_curve, m_cr, m_n_chs, n_cr_t, n_cr_tf, rho, BuckleCurve, InteractionMethod, MomentDiagram,
+};
+use crate::erc::NSEN_1993_1_2::{
+    alpha, beta_m, f_4_22, f_4_6, f_4_7, k_lt_fi, k_y_fi, k_y_theta, k_z_fi,
+};
+use crate::erc::NSEN_1993_1_5::{
+    c, f_5_1, f_5_2, f_5_3, f_5_4, f_5_8, f_6_1, f_6_10, f_6_11, f_6_12, f_6_3, f_6_4, f_6_5,
+    f_6_8, f_6_9, f_7_1, f_7_2, k_f, k_tau, khi_w, sigma_e, EndPost, TransverseLoad,
+};
+use crate::load::loadcase::LoadCase;
 use crate::mat::steel::Steel;
+use crate::mmb::buckling::BucklingLength;
 use crate::{crs::rect::Rect, mat::Material};
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{Axis, LimitStateType};
 
+#[derive(Serialize, Deserialize)]
 pub struct ColumnBeam {
     pub crs: Box<dyn CrossSection>,
     pub mat: Steel,
+    pub annex: NationalAnnex,
+    /// Net area at holes for fasteners, when the member has any
+    #[serde(default)]
+    pub area_net: Option<f64>,
+    #[serde(default)]
+    pub fabrication: Fabrication,
+}
+
+/// Buckling lengths and moment distributions of a member in bending and axial compression,
+/// 6.3.3
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MemberBuckling {
+    pub lk_y: f64,
+    pub lk_z: f64,
+    /// Elastic critical moment, or `None` when the member is not susceptible to torsional
+    /// deformations
+    pub m_cr: Option<f64>,
+    /// Moment distribution about the y-axis, also taken between the lateral restraints
+    pub diagram_y: MomentDiagram,
+    /// Moment distribution about the z-axis
+    pub diagram_z: MomentDiagram,
+}
+
+impl MemberBuckling {
+    /// Buckling lengths about y and z taken from `lengths`
+    #[must_use]
+    pub fn from_lengths(
+        lengths: &BucklingLength,
+        m_cr: Option<f64>,
+        diagram_y: MomentDiagram,
+        diagram_z: MomentDiagram,
+    ) -> Self {
+        Self {
+            lk_y: lengths.lk(Axis::Y),
+            lk_z: lengths.lk(Axis::Z),
+            m_cr,
+            diagram_y,
+            diagram_z,
+        }
+    }
+}
+
+/// Web panel of an I-section for shear buckling to NS-EN 1993-1-5 section 5
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WebPanel {
+    /// Spacing of the transverse stiffeners, or the distance between the supports when the web
+    /// is only stiffened there
+    pub a: f64,
+    pub end_post: EndPost,
+}
+
+/// Concentrated force on the flange of an I-section, for resistance of the web to transverse
+/// forces to NS-EN 1993-1-5 section 6
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Patch {
+    /// Transverse force in [N]
+    pub force: f64,
+    /// Stiff bearing length in [mm], taken as no more than the depth of the web
+    pub s_s: f64,
+    pub load: TransverseLoad,
 }
 
 impl Default for ColumnBeam {
@@ -16,13 +94,41 @@ impl Default for ColumnBeam {
         Self {
             crs: Box::new(Rect::default()),
             mat: Steel::default(),
+            annex: NationalAnnex::default(),
+            area_net: None,
+            fabrication: Fabrication::default(),
         }
     }
 }
 impl ColumnBeam {
     #[must_use]
     pub fn new(crs: Box<dyn CrossSection>, mat: Steel) -> Self {
-        Self { crs, mat }
+        Self {
+            crs,
+            mat,
+            annex: NationalAnnex::default(),
+            area_net: None,
+            fabrication: Fabrication::default(),
+        }
+    }
+    /// Designs the member to the given National Annex, including the partial factors of the steel
+    #[must_use]
+    pub const fn with_annex(mut self, annex: NationalAnnex) -> Self {
+        self.mat = self.mat.with_annex(&annex);
+        self.annex = annex;
+        self
+    }
+    /// Member made by `fabrication` rather than hot rolling, used to choose its buckling curves
+    #[must_use]
+    pub const fn with_fabrication(mut self, fabrication: Fabrication) -> Self {
+        self.fabrication = fabrication;
+        self
+    }
+    /// Member with holes for fasteners, where `area_net` is the net area of the weakest section
+    #[must_use]
+    pub const fn with_net_area(mut self, area_net: f64) -> Self {
+        self.area_net = Some(area_net);
+        self
     }
     #[allow(non_snake_case)]
     #[must_use]
@@ -32,9 +138,41 @@ impl ColumnBeam {
     #[allow(non_snake_case)]
     #[must_use]
     pub fn V_pl(&self, axis: Axis, limit_state_type: &LimitStateType) -> f64 {
-        self.mat.f_y(limit_state_type) * self.crs.area_shear(axis) / 3f64.sqrt()
+        self.mat.f_y(limit_state_type) * self.area_shear(axis) / 3f64.sqrt()
+    }
+    /// Shear area along `axis`, raised to `eta h_w t_w` of 6.2.6(3)(a) along the webs of
+    /// I-sections with `eta` from the National Annex
+    fn area_shear(&self, axis: Axis) -> f64 {
+        let area = self.crs.area_shear(axis);
+        match (axis, self.crs.web()) {
+            (Axis::Z, Some((h_w, t_w))) if self.crs.shape() == Shape::IBeam => {
+                let eta = self.annex.eta(self.mat.f_y(&LimitStateType::K));
+                area.max(eta * h_w * t_w)
+            }
+            _ => area,
+        }
     }
 
+    /// Flexural buckling curve about `axis` from the shape and fabrication of the section,
+    /// Table 6.2
+    #[must_use]
+    pub fn buckle_curve(&self, axis: Axis) -> BuckleCurve {
+        let t_f = self
+            .crs
+            .flange()
+            .map_or_else(|| self.crs.t_max(), |(_, t_f)| t_f);
+        buckling_curve(
+            self.crs.shape(),
+            self.fabrication,
+            self.crs.height() / self.crs.width(),
+            t_f,
+            self.mat.is_s460(),
+            axis,
+        )
+    }
+
+    /// Flexural buckling resistance for the buckling length `lk` on the curve `buckle_curve`,
+    /// 6.3.1
     #[must_use]
     pub fn buckle_cap(
         &self,
@@ -43,19 +181,64 @@ impl ColumnBeam {
         buckle_curve: &BuckleCurve,
         limit_state_type: &LimitStateType,
     ) -> f64 {
-        {
-            // Eurocode 1993 buckling
-            let ncr = self.euler_load(lk, axis);
-            let lambda = _compute_lamba(self.crs.area(), self.mat.f_y(&LimitStateType::K), ncr);
-            let phi = _compute_phi(buckle_curve.alpha(), lambda);
-            let khi_buckle_reduction_factor = f_6_49(phi, lambda);
-            f_6_47(
-                khi_buckle_reduction_factor,
-                self.crs.area(),
-                self.mat.f_y(&LimitStateType::K),
-                self.mat.gamma_m1(limit_state_type),
-            )
-        }
+        f_6_47(
+            self.khi(lk, axis, Some(*buckle_curve)),
+            self.crs.area(),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m1(limit_state_type),
+        )
+    }
+    /// Flexural buckling resistance for the buckling length `lk`, 6.3.1, with the curve of
+    /// [`ColumnBeam::buckle_curve`]
+    #[must_use]
+    pub fn buckle_cap_auto(&self, lk: f64, axis: Axis, limit_state_type: &LimitStateType) -> f64 {
+        self.buckle_cap(lk, axis, &self.buckle_curve(axis), limit_state_type)
+    }
+    /// Compression resistance for the lowest of flexural buckling about either axis with the
+    /// buckling lengths `lk_y` and `lk_z`, and torsional or torsional-flexural buckling with the
+    /// torsional buckling length `l_t`, 6.3.1
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_b(&self, lk_y: f64, lk_z: f64, l_t: f64, limit_state_type: &LimitStateType) -> f64 {
+        self.buckle_cap_auto(lk_y, Axis::Y, limit_state_type)
+            .min(self.buckle_cap_auto(lk_z, Axis::Z, limit_state_type))
+            .min(self.torsional_buckle_cap(lk_y, lk_z, l_t, limit_state_type))
+    }
+    /// Torsional and torsional-flexural buckling resistance, with the slenderness of 6.52 and
+    /// the buckling curve about the z-axis, 6.3.1.4
+    #[must_use]
+    pub fn torsional_buckle_cap(
+        &self,
+        lk_y: f64,
+        lk_z: f64,
+        l_t: f64,
+        limit_state_type: &LimitStateType,
+    ) -> f64 {
+        f_6_47(
+            self.reduction(
+                self.torsional_flexural_load(lk_y, lk_z, l_t),
+                self.buckle_curve(Axis::Z),
+            ),
+            self.crs.area(),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m1(limit_state_type),
+        )
+    }
+    /// Slenderness for flexural buckling about `axis`, 6.50
+    #[must_use]
+    pub fn lambda(&self, lk: f64, axis: Axis) -> f64 {
+        (self.N_pl(&LimitStateType::K) / self.euler_load(lk, axis)).sqrt()
+    }
+    /// Reduction factor for flexural buckling about `axis`, 6.49
+    #[must_use]
+    pub fn khi(&self, lk: f64, axis: Axis, buckle_curve: Option<BuckleCurve>) -> f64 {
+        let curve = buckle_curve.unwrap_or_else(|| self.buckle_curve(axis));
+        self.reduction(self.euler_load(lk, axis), curve)
+    }
+    /// Reduction factor for the elastic critical force `n_cr` on the buckling curve `curve`
+    fn reduction(&self, n_cr: f64, curve: BuckleCurve) -> f64 {
+        let lambda = _compute_lamba(self.crs.area(), self.mat.f_y(&LimitStateType::K), n_cr);
+        f_6_49(_compute_phi(curve.alpha(), lambda), lambda)
     }
     #[allow(non_snake_case)]
     #[must_use]
@@ -78,11 +261,887 @@ impl ColumnBeam {
         let I = self.crs.I(axis);
         I * self.mat.E()
     }
+    /// St. Venant torsional stiffness
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn GI_t(&self) -> f64 {
+        self.mat.G() * self.crs.I(Axis::X)
+    }
 
     #[must_use]
     pub fn euler_load(&self, lk: f64, axis: Axis) -> f64 {
         self.EI(axis) * (std::f64::consts::PI / lk).powi(2)
     }
+    /// Elastic critical force for torsional buckling with the torsional buckling length `l_t`
+    #[must_use]
+    pub fn torsional_load(&self, l_t: f64) -> f64 {
+        n_cr_t(
+            self.polar_radius(),
+            self.GI_t(),
+            self.mat.E() * self.crs.I_w(),
+            l_t,
+        )
+    }
+    /// Lowest elastic critical force of the torsional modes, coupling torsion with flexure when
+    /// the shear centre is offset from the centroid, and pure torsional buckling otherwise
+    #[must_use]
+    pub fn torsional_flexural_load(&self, lk_y: f64, lk_z: f64, l_t: f64) -> f64 {
+        let n_cr_t = self.torsional_load(l_t);
+        let offset = self.crs.shear_centre();
+        if offset == (0.0, 0.0) {
+            return n_cr_t;
+        }
+        n_cr_tf(
+            (
+                self.euler_load(lk_y, Axis::Y),
+                self.euler_load(lk_z, Axis::Z),
+            ),
+            n_cr_t,
+            offset,
+            self.polar_radius(),
+        )
+    }
+    /// Polar radius of gyration about the shear centre
+    fn polar_radius(&self) -> f64 {
+        let (y_0, z_0) = self.crs.shear_centre();
+        let area = self.crs.area();
+        let i_c = (self.crs.I(Axis::Y) + self.crs.I(Axis::Z)) / area;
+        y_0.mul_add(y_0, z_0.mul_add(z_0, i_c)).sqrt()
+    }
+
+    /*------------- CROSS-SECTION RESISTANCE, 6.2 -------------*/
+    // Plastic resistances, valid for class 1 and 2 cross-sections. Moments are taken about
+    // `axis`, while shear forces act along it
+
+    /// Tension resistance, the lesser of gross yielding 6.6 and net section rupture 6.7
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_t(&self, limit_state_type: &LimitStateType) -> f64 {
+        let n_pl = f_6_6(
+            self.crs.area(),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m0(limit_state_type),
+        );
+        self.area_net.map_or(n_pl, |area_net| {
+            n_pl.min(f_6_7(
+                area_net,
+                self.mat.f_u(&LimitStateType::K),
+                self.mat.gamma_m2(limit_state_type),
+            ))
+        })
+    }
+    /// Compression resistance, 6.10
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_c(&self, limit_state_type: &LimitStateType) -> f64 {
+        f_6_6(
+            self.crs.area(),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m0(limit_state_type),
+        )
+    }
+    /// Bending resistance, 6.13
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_c(&self, axis: Axis, limit_state_type: &LimitStateType) -> f64 {
+        f_6_13(
+            self.crs.w_pl(axis),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m0(limit_state_type),
+        )
+    }
+    /// Shear resistance, 6.18, reduced for the torsional moment `t_ed` by 6.26 for I-sections
+    /// and by 6.28 for other sections
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn V_c(&self, axis: Axis, t_ed: f64, limit_state_type: &LimitStateType) -> f64 {
+        let (fy, gamma_m0) = (
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m0(limit_state_type),
+        );
+        let v_pl = f_6_18(self.area_shear(axis), fy, gamma_m0);
+        let tau_t = t_ed.abs() / self.crs.w_el(Axis::X);
+        if self.crs.shape() == Shape::IBeam {
+            f_6_26(tau_t, fy, gamma_m0, v_pl)
+        } else {
+            f_6_28(tau_t, fy, gamma_m0, v_pl)
+        }
+    }
+    /// Torsional resistance at first yield of the St. Venant shear stresses, 6.2.7 with the
+    /// criterion 6.1
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn T_Rd(&self, limit_state_type: &LimitStateType) -> f64 {
+        self.crs.w_el(Axis::X) * self.mat.f_y(limit_state_type) / 3f64.sqrt()
+    }
+    /// Bending resistance reduced for the shear force `v_ed` in the plane of bending and the
+    /// torsional moment `t_ed`, 6.2.8. Uses 6.30 about the major axis of I-sections and the
+    /// reduced yield strength over the whole section otherwise, which is conservative unless
+    /// the section is solid
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_V(&self, axis: Axis, v_ed: f64, t_ed: f64, limit_state_type: &LimitStateType) -> f64 {
+        let m_c = self.M_c(axis, limit_state_type);
+        let rho = rho(
+            v_ed.abs(),
+            self.V_c(shear_axis(axis), t_ed, limit_state_type),
+        );
+        let (fy, gamma_m0) = (
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m0(limit_state_type),
+        );
+        match (axis, self.crs.web()) {
+            (Axis::Y, Some((h_w, t_w))) if self.crs.shape() == Shape::IBeam => {
+                f_6_30(self.crs.w_pl(axis), rho, h_w * t_w, t_w, fy, gamma_m0).min(m_c)
+            }
+            _ => f_6_13(self.crs.w_pl(axis), f_6_29(fy, rho), gamma_m0),
+        }
+    }
+    /// Whether the webs are slender enough to require a shear buckling check to
+    /// NS-EN 1993-1-5 section 5, 6.2.6(6)
+    #[must_use]
+    pub fn shear_buckling(&self) -> bool {
+        let fy = self.mat.f_y(&LimitStateType::K);
+        self.crs
+            .web()
+            .is_some_and(|(h_w, t_w)| f_6_22(h_w, t_w, epsilon(fy), self.annex.eta(fy)))
+    }
+    /// Plastic bending resistance reduced for the axial force `n_ed`, 6.2.9.1, by 6.36 to 6.38
+    /// for I-sections, 6.39 and 6.40 for rectangular and `M_pl (1 - n^1.7)` for circular hollow
+    /// sections, 6.32 for solid rectangles, and linearly for other sections
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_N(&self, axis: Axis, n_ed: f64, limit_state_type: &LimitStateType) -> f64 {
+        let (n_ed, n_pl) = (n_ed.abs(), self.N_c(limit_state_type));
+        let (m_pl, n) = (self.M_c(axis, limit_state_type), (n_ed / n_pl).min(1.0));
+        let area = self.crs.area();
+        match (self.crs.shape(), self.crs.web(), self.crs.flange()) {
+            (Shape::IBeam, Some((h_w, t_w)), Some((b, t_f))) => {
+                let n_w = f_6_6(
+                    h_w * t_w,
+                    self.mat.f_y(&LimitStateType::K),
+                    self.mat.gamma_m0(limit_state_type),
+                );
+                let a = (2.0 * b).mul_add(-t_f, area) / area;
+                match axis {
+                    Axis::Z if f_6_35(n_ed, n_w) => m_pl,
+                    Axis::Z => f_6_38(m_pl, n, a),
+                    _ if f_6_33(n_ed, n_pl, n_w) => m_pl,
+                    _ => f_6_36(m_pl, n, a),
+                }
+            }
+            (Shape::Box, Some((_, t_w)), Some((b, t_f))) => {
+                let a = match axis {
+                    Axis::Z => (2.0 * self.crs.height()).mul_add(-t_w, area) / area,
+                    _ => (2.0 * b).mul_add(-t_f, area) / area,
+                };
+                f_6_39(m_pl, n, a)
+            }
+            (Shape::Tube, ..) => m_n_chs(m_pl, n),
+            (Shape::Rect, ..) => f_6_32(m_pl, n),
+            _ => m_pl * (1.0 - n),
+        }
+    }
+
+    /// Tension, 6.2.3
+    #[must_use]
+    pub fn tension_check(&self, load_case: &LoadCase, limit_state_type: &LimitStateType) -> Check {
+        Check::new("6.2.3", load_case.N.max(0.0) / self.N_t(limit_state_type))
+    }
+    /// Compression, 6.2.4
+    #[must_use]
+    pub fn compression_check(
+        &self,
+        load_case: &LoadCase,
+        limit_state_type: &LimitStateType,
+    ) -> Check {
+        Check::new(
+            "6.2.4",
+            (-load_case.N).max(0.0) / self.N_c(limit_state_type),
+        )
+    }
+    /// Bending about `axis`, 6.2.5
+    #[must_use]
+    pub fn bending_check(
+        &self,
+        load_case: &LoadCase,
+        axis: Axis,
+        limit_state_type: &LimitStateType,
+    ) -> Check {
+        Check::new(
+            "6.2.5",
+            moment(load_case, axis) / self.M_c(axis, limit_state_type),
+        )
+    }
+    /// Shear along `axis`, 6.2.6, or 6.2.7(9) when combined with torsion
+    #[must_use]
+    pub fn shear_check(
+        &self,
+        load_case: &LoadCase,
+        axis: Axis,
+        limit_state_type: &LimitStateType,
+    ) -> Check {
+        let clause = if load_case.Mx == 0.0 {
+            "6.2.6"
+        } else {
+            "6.2.7(9)"
+        };
+        let v_rd = self.V_c(axis, load_case.Mx, limit_state_type);
+        Check::new(clause, shear(load_case, axis) / v_rd)
+    }
+    /// St. Venant torsion, 6.2.7
+    #[must_use]
+    pub fn torsion_check(&self, load_case: &LoadCase, limit_state_type: &LimitStateType) -> Check {
+        Check::new("6.2.7", load_case.Mx.abs() / self.T_Rd(limit_state_type))
+    }
+    /// Bending about `axis` with the shear force in the plane of bending, 6.2.8
+    #[must_use]
+    pub fn bending_shear_check(
+        &self,
+        load_case: &LoadCase,
+        axis: Axis,
+        limit_state_type: &LimitStateType,
+    ) -> Check {
+        let m_rd = self.M_V(
+            axis,
+            shear(load_case, shear_axis(axis)),
+            load_case.Mx,
+            limit_state_type,
+        );
+        Check::new("6.2.8", moment(load_case, axis) / m_rd)
+    }
+    /// Bending and axial force, 6.2.9, by the bi-axial criterion 6.41. The reduction for shear
+    /// of 6.2.10 is applied to the reduced plastic moments as the ratio of `M_V` to `M_c`
+    ///
+    /// The left-hand side of 6.41 is not proportional to the load, so the utilisation is
+    /// reported as the inverse of the load factor that brings it to unity
+    #[must_use]
+    pub fn bending_axial_check(
+        &self,
+        load_case: &LoadCase,
+        limit_state_type: &LimitStateType,
+    ) -> Check {
+        let fails = |factor| self.f_6_41(&load_case.scaled(factor), limit_state_type) >= 1.0;
+        let mut high = 1.0;
+        for _ in 0..64 {
+            if fails(high) {
+                break;
+            }
+            high *= 2.0;
+        }
+        if !fails(high) {
+            return Check::new("6.2.9", 0.0);
+        }
+        let mut low = 0.0;
+        for _ in 0..64 {
+            let mid = 0.5 * (low + high);
+            if fails(mid) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+        Check::new("6.2.9", 2.0 / (low + high))
+    }
+    /// Left-hand side of 6.41, or the axial force alone where it governs
+    fn f_6_41(&self, load_case: &LoadCase, limit_state_type: &LimitStateType) -> f64 {
+        let m_rd = |axis| {
+            let m_v = self.M_V(
+                axis,
+                shear(load_case, shear_axis(axis)),
+                load_case.Mx,
+                limit_state_type,
+            );
+            self.M_N(axis, load_case.N, limit_state_type) * m_v / self.M_c(axis, limit_state_type)
+        };
+        let n = load_case.N.abs() / self.N_c(limit_state_type);
+        let (alpha, beta) = f_6_41_exponents(self.crs.shape(), n);
+        let utilisation = f_6_41(
+            moment(load_case, Axis::Y),
+            m_rd(Axis::Y),
+            moment(load_case, Axis::Z),
+            m_rd(Axis::Z),
+            alpha,
+            beta,
+        );
+        // 6.41 vanishes without moments, where the axial force alone governs
+        utilisation.max(n)
+    }
+    /// Every cross-section check of 6.2 for the load case
+    #[must_use]
+    pub fn cross_section_checks(
+        &self,
+        load_case: &LoadCase,
+        limit_state_type: &LimitStateType,
+    ) -> Vec<Check> {
+        let mut checks = vec![
+            self.tension_check(load_case, limit_state_type),
+            self.compression_check(load_case, limit_state_type),
+        ];
+        for axis in [Axis::Y, Axis::Z] {
+            checks.push(self.bending_check(load_case, axis, limit_state_type));
+            checks.push(self.shear_check(load_case, axis, limit_state_type));
+        }
+        checks.push(self.torsion_check(load_case, limit_state_type));
+        for axis in [Axis::Y, Axis::Z] {
+            checks.push(self.bending_shear_check(load_case, axis, limit_state_type));
+        }
+        checks.push(self.bending_axial_check(load_case, limit_state_type));
+        checks
+    }
+
+    /*------------- LATERAL TORSIONAL BUCKLING, 6.3.2 -------------*/
+
+    /// Elastic critical moment of a doubly symmetric section over the `length` between lateral
+    /// restraints, with the end conditions `k` and `k_w` and the load applied `z_g` above the
+    /// shear centre
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_cr(&self, length: f64, diagram: &MomentDiagram, k: f64, k_w: f64, z_g: f64) -> f64 {
+        m_cr(
+            diagram.factors(k),
+            k,
+            k_w,
+            length,
+            self.EI(Axis::Z),
+            self.GI_t(),
+            self.crs.I_w() / self.crs.I(Axis::Z),
+            z_g,
+            0.0,
+        )
+    }
+    /// Slenderness for lateral torsional buckling, 6.3.2.2(1)
+    #[must_use]
+    pub fn lambda_lt(&self, m_cr: f64) -> f64 {
+        (self.M_pl(Axis::Y, &LimitStateType::K) / m_cr).sqrt()
+    }
+    /// Reduction factor for lateral torsional buckling, by 6.3.2.3 with the modification for
+    /// the moment distribution `diagram` when it is given, and by 6.3.2.2 otherwise. Sections
+    /// other than I-sections always use 6.3.2.2
+    #[must_use]
+    pub fn khi_lt(&self, m_cr: f64, diagram: Option<&MomentDiagram>) -> f64 {
+        let lambda = self.lambda_lt(m_cr);
+        let h_over_b = self.crs.height() / self.crs.width();
+        match diagram {
+            Some(diagram) if self.crs.shape() == Shape::IBeam => {
+                let curve = self
+                    .annex
+                    .ltb_curve(self.fabrication == Fabrication::Welded, h_over_b);
+                let khi = f_6_57(
+                    curve.alpha(),
+                    lambda,
+                    self.annex.lambda_lt0(),
+                    self.annex.beta_lt(),
+                );
+                f_6_58(khi, diagram.k_c(), lambda)
+            }
+            _ => f_6_56(
+                ltb_curve(self.crs.shape(), self.fabrication, h_over_b).alpha(),
+                lambda,
+            ),
+        }
+    }
+    /// Lateral torsional buckling resistance about the y-axis, 6.55
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_b(
+        &self,
+        m_cr: f64,
+        diagram: Option<&MomentDiagram>,
+        limit_state_type: &LimitStateType,
+    ) -> f64 {
+        f_6_55(
+            self.khi_lt(m_cr, diagram),
+            self.crs.w_pl(Axis::Y),
+            self.mat.f_y(&LimitStateType::K),
+            self.mat.gamma_m1(limit_state_type),
+        )
+    }
+    /// Lateral torsional buckling, 6.3.2.1, or the cross-section resistance when 6.3.2.2(4)
+    /// allows buckling to be ignored
+    #[must_use]
+    pub fn ltb_check(
+        &self,
+        load_case: &LoadCase,
+        m_cr: f64,
+        diagram: Option<&MomentDiagram>,
+        limit_state_type: &LimitStateType,
+    ) -> Check {
+        let my = moment(load_case, Axis::Y);
+        let lambda_lt0 = match diagram {
+            Some(_) if self.crs.shape() == Shape::IBeam => self.annex.lambda_lt0(),
+            _ => 0.2,
+        };
+        if self.lambda_lt(m_cr) <= lambda_lt0 || my / m_cr <= lambda_lt0.powi(2) {
+            return Check::new("6.3.2.2(4)", my / self.M_c(Axis::Y, limit_state_type));
+        }
+        Check::new("6.3.2.1", my / self.M_b(m_cr, diagram, limit_state_type))
+    }
+
+    /*------------- SHEAR BUCKLING, NS-EN 1993-1-5 5 AND 7.1 -------------*/
+    // I-sections only, other sections give `None`. The web and flanges share the yield
+    // strength of the steel
+
+    /// Clear depth and thickness of the web, and width and thickness of the flanges
+    fn plate_girder(&self) -> Option<((f64, f64), (f64, f64))> {
+        if self.crs.shape() != Shape::IBeam {
+            return None;
+        }
+        Some((self.crs.web()?, self.crs.flange()?))
+    }
+    /// Slenderness of the web panel in shear, 5.3 with the buckling coefficient of A.5
+    #[must_use]
+    pub fn lambda_w(&self, panel: &WebPanel) -> Option<f64> {
+        let ((h_w, t_w), _) = self.plate_girder()?;
+        let tau_cr = f_5_4(k_tau(panel.a, h_w, 0.0), sigma_e(t_w, h_w));
+        Some(f_5_3(self.mat.f_y(&LimitStateType::K), tau_cr))
+    }
+    /// Contribution from the web to the shear buckling resistance, 5.2
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn V_bw(&self, panel: &WebPanel, limit_state_type: &LimitStateType) -> Option<f64> {
+        let ((h_w, t_w), _) = self.plate_girder()?;
+        let fy = self.mat.f_y(&LimitStateType::K);
+        let khi = khi_w(self.lambda_w(panel)?, self.annex.eta(fy), panel.end_post);
+        Some(f_5_2(
+            khi,
+            fy,
+            h_w,
+            t_w,
+            self.mat.gamma_m1(limit_state_type),
+        ))
+    }
+    /// Moment resistance of the flanges alone, reduced for the axial force `n_ed` by 5.4(2)
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_f(&self, n_ed: f64, limit_state_type: &LimitStateType) -> Option<f64> {
+        let (_, (b_f, t_f)) = self.plate_girder()?;
+        let n_f = b_f * t_f * self.mat.f_y(limit_state_type);
+        Some(n_f * (self.crs.height() - t_f) * (1.0 - n_ed.abs() / (2.0 * n_f)).max(0.0))
+    }
+    /// Contribution from the flanges to the shear buckling resistance, 5.4, with the flange
+    /// width limited to 15 epsilon `t_f` on each side of the web
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn V_bf(
+        &self,
+        panel: &WebPanel,
+        m_ed: f64,
+        n_ed: f64,
+        limit_state_type: &LimitStateType,
+    ) -> Option<f64> {
+        let ((h_w, t_w), (b_f, t_f)) = self.plate_girder()?;
+        let fy = self.mat.f_y(&LimitStateType::K);
+        let b_f = b_f.min((30.0 * epsilon(fy)).mul_add(t_f, t_w));
+        Some(f_5_8(
+            b_f,
+            t_f,
+            fy,
+            c(panel.a, b_f, t_f, fy, t_w, h_w, fy),
+            self.mat.gamma_m1(limit_state_type),
+            m_ed,
+            self.M_f(n_ed, limit_state_type)?,
+        ))
+    }
+    /// Shear buckling resistance for the bending moment `m_ed` and axial force `n_ed`, 5.1
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn V_b(
+        &self,
+        panel: &WebPanel,
+        m_ed: f64,
+        n_ed: f64,
+        limit_state_type: &LimitStateType,
+    ) -> Option<f64> {
+        let ((h_w, t_w), _) = self.plate_girder()?;
+        let fy = self.mat.f_y(&LimitStateType::K);
+        Some(f_5_1(
+            self.V_bw(panel, limit_state_type)?,
+            self.V_bf(panel, m_ed, n_ed, limit_state_type)?,
+            self.annex.eta(fy),
+            fy,
+            h_w,
+            t_w,
+            self.mat.gamma_m1(limit_state_type),
+        ))
+    }
+    /// Shear buckling of the web under the shear force along z, NS-EN 1993-1-5 5.5
+    #[must_use]
+    pub fn shear_buckling_check(
+        &self,
+        load_case: &LoadCase,
+        panel: &WebPanel,
+        limit_state_type: &LimitStateType,
+    ) -> Option<Check> {
+        let v_b = self.V_b(
+            panel,
+            moment(load_case, Axis::Y),
+            load_case.N,
+            limit_state_type,
+        )?;
+        Some(Check::new(
+            "NS-EN 1993-1-5 5.5",
+            shear(load_case, Axis::Z) / v_b,
+        ))
+    }
+    /// Interaction between bending about y and shear buckling of the web, NS-EN 1993-1-5 7.1
+    #[must_use]
+    pub fn bending_shear_buckling_check(
+        &self,
+        load_case: &LoadCase,
+        panel: &WebPanel,
+        limit_state_type: &LimitStateType,
+    ) -> Option<Check> {
+        let m_pl = self.M_pl(Axis::Y, limit_state_type);
+        Some(Check::new(
+            "NS-EN 1993-1-5 7.1",
+            f_7_1(
+                moment(load_case, Axis::Y) / m_pl,
+                shear(load_case, Axis::Z) / self.V_bw(panel, limit_state_type)?,
+                self.M_f(load_case.N, limit_state_type)?,
+                m_pl,
+            ),
+        ))
+    }
+    /// Effective length of the web resisting the transverse force, 6.2. The factor `m_2` of
+    /// 6.9 assumes a slender web and is dropped if the slenderness turns out to be at most 0.5
+    fn l_eff(&self, patch: &Patch, panel: &WebPanel) -> Option<f64> {
+        let ((h_w, t_w), (b_f, t_f)) = self.plate_girder()?;
+        let (fy, e) = (self.mat.f_y(&LimitStateType::K), self.mat.E());
+        let s_s = patch.s_s.min(h_w);
+        let k = k_f(&patch.load, h_w, panel.a, s_s);
+        let f_cr = f_6_5(k, e, t_w, h_w);
+        let m_1 = f_6_8(fy, b_f, fy, t_w);
+        let loaded_length = |lambda_f: f64| {
+            let m_2 = f_6_9(h_w, t_f, lambda_f);
+            match patch.load {
+                TransverseLoad::C { c } => {
+                    f_6_12(f_6_11(k, e, t_w, fy, h_w, s_s, c), t_f, m_1, m_2)
+                }
+                _ => f_6_10(s_s, t_f, m_1, m_2, panel.a),
+            }
+        };
+        let mut l_y = loaded_length(1.0);
+        let mut lambda_f = f_6_4(l_y, t_w, fy, f_cr);
+        if lambda_f <= 0.5 {
+            l_y = loaded_length(lambda_f);
+            lambda_f = f_6_4(l_y, t_w, fy, f_cr);
+        }
+        Some(f_6_3(lambda_f) * l_y)
+    }
+    /// Design resistance of the web to the transverse force of `patch`, 6.1
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn F_Rd(
+        &self,
+        patch: &Patch,
+        panel: &WebPanel,
+        limit_state_type: &LimitStateType,
+    ) -> Option<f64> {
+        let ((_, t_w), _) = self.plate_girder()?;
+        Some(f_6_1(
+            self.mat.f_y(&LimitStateType::K),
+            self.l_eff(patch, panel)?,
+            t_w,
+            self.mat.gamma_m1(limit_state_type),
+        ))
+    }
+    /// Resistance of the web to the transverse force of `patch`, NS-EN 1993-1-5 6.6
+    #[must_use]
+    pub fn patch_loading_check(
+        &self,
+        patch: &Patch,
+        panel: &WebPanel,
+        limit_state_type: &LimitStateType,
+    ) -> Option<Check> {
+        let f_rd = self.F_Rd(patch, panel, limit_state_type)?;
+        Some(Check::new("NS-EN 1993-1-5 6.6", patch.force / f_rd))
+    }
+    /// Interaction between the transverse force of `patch`, bending about y and axial force,
+    /// NS-EN 1993-1-5 7.2
+    ///
+    /// `eta_1` follows 4.14 with the gross area and elastic section modulus, so the effective
+    /// section of class 4 webs is not accounted for
+    #[must_use]
+    pub fn bending_patch_loading_check(
+        &self,
+        load_case: &LoadCase,
+        patch: &Patch,
+        panel: &WebPanel,
+        limit_state_type: &LimitStateType,
+    ) -> Option<Check> {
+        let eta_2 = patch.force / self.F_Rd(patch, panel, limit_state_type)?;
+        let eta_1 = load_case.N.abs() / self.N_pl(limit_state_type)
+            + moment(load_case, Axis::Y) / self.M_el(Axis::Y, limit_state_type);
+        Some(Check::new("NS-EN 1993-1-5 7.2", f_7_2(eta_1, eta_2)))
+    }
+
+    /*------------- BENDING AND AXIAL COMPRESSION, 6.3.3 -------------*/
+    // Class 1 and 2 cross-sections. Tension is taken as no axial force
+
+    /// Uniform members in bending and axial compression, returning the utilisations of 6.61 and
+    /// 6.62 with the interaction factors of `method`
+    #[allow(clippy::similar_names)]
+    #[must_use]
+    pub fn member_checks(
+        &self,
+        load_case: &LoadCase,
+        buckling: &MemberBuckling,
+        method: InteractionMethod,
+        limit_state_type: &LimitStateType,
+    ) -> [Check; 2] {
+        let n_ed = (-load_case.N).max(0.0);
+        let (m_y, m_z) = (moment(load_case, Axis::Y), moment(load_case, Axis::Z));
+        let fy = self.mat.f_y(&LimitStateType::K);
+        let g1 = self.mat.gamma_m1(limit_state_type);
+        let n_rd = self.crs.area() * fy / g1;
+        let khi_y = self.khi(buckling.lk_y, Axis::Y, None);
+        let khi_z = self.khi(buckling.lk_z, Axis::Z, None);
+        let khi_lt = buckling
+            .m_cr
+            .map_or(1.0, |m_cr| self.khi_lt(m_cr, Some(&buckling.diagram_y)));
+        let n_y = n_ed / (khi_y * n_rd);
+        let n_z = n_ed / (khi_z * n_rd);
+        let m_y = m_y / (khi_lt * self.crs.w_pl(Axis::Y) * fy / g1);
+        let m_z = m_z / (self.crs.w_pl(Axis::Z) * fy / g1);
+        let (k_yy, k_yz, k_zy, k_zz) = match method {
+            InteractionMethod::AnnexA => {
+                self.annex_a_factors(load_case, buckling, khi_lt, limit_state_type)
+            }
+            InteractionMethod::AnnexB => annex_b_factors(
+                (
+                    self.lambda(buckling.lk_y, Axis::Y),
+                    self.lambda(buckling.lk_z, Axis::Z),
+                ),
+                n_y,
+                n_z,
+                (
+                    buckling.diagram_y.c_m(),
+                    buckling.diagram_z.c_m(),
+                    buckling.diagram_y.c_m(),
+                ),
+                self.crs.shape() == Shape::IBeam,
+                buckling.m_cr.is_some() && self.crs.shape() == Shape::IBeam,
+            ),
+        };
+        [
+            Check::new("6.3.3 (6.61)", f_6_61(n_y, m_y, m_z, k_yy, k_yz)),
+            Check::new("6.3.3 (6.62)", f_6_62(n_z, m_y, m_z, k_zy, k_zz)),
+        ]
+    }
+    /// Interaction factors `(k_yy, k_yz, k_zy, k_zz)` of Annex A, Table A.1, with the
+    /// equivalent uniform moment factors of Table A.2
+    #[allow(clippy::similar_names)]
+    fn annex_a_factors(
+        &self,
+        load_case: &LoadCase,
+        buckling: &MemberBuckling,
+        khi_lt: f64,
+        limit_state_type: &LimitStateType,
+    ) -> (f64, f64, f64, f64) {
+        let n_ed = (-load_case.N).max(0.0);
+        let (m_y, m_z) = (moment(load_case, Axis::Y), moment(load_case, Axis::Z));
+        let (area, fy) = (self.crs.area(), self.mat.f_y(&LimitStateType::K));
+        let g1 = self.mat.gamma_m1(limit_state_type);
+        let (lk_y, lk_z) = (buckling.lk_y, buckling.lk_z);
+        let ncr_y = self.euler_load(lk_y, Axis::Y);
+        let ncr_z = self.euler_load(lk_z, Axis::Z);
+        let ncr_t = self.torsional_load(lk_z);
+        let ncr_tf = self.torsional_flexural_load(lk_y, lk_z, lk_z);
+        let mu = |khi: f64, ncr: f64| (1.0 - n_ed / ncr) / (1.0 - khi * n_ed / ncr);
+        let mu_y = mu(self.khi(lk_y, Axis::Y, None), ncr_y);
+        let mu_z = mu(self.khi(lk_z, Axis::Z, None), ncr_z);
+        let w_y = (self.crs.w_pl(Axis::Y) / self.crs.w_el(Axis::Y)).min(1.5);
+        let w_z = (self.crs.w_pl(Axis::Z) / self.crs.w_el(Axis::Z)).min(1.5);
+        let n_pl = n_ed / (area * fy / g1);
+        let a_lt = (1.0 - self.crs.I(Axis::X) / self.crs.I(Axis::Y)).max(0.0);
+        let lambda_z = self.lambda(lk_z, Axis::Z);
+        let lambda_max = self.lambda(lk_y, Axis::Y).max(lambda_z);
+
+        let c_1 = buckling.diagram_y.factors(1.0).0;
+        let lambda_0 = buckling.m_cr.map_or(0.0, |m_cr| self.lambda_lt(m_cr / c_1));
+        let c_my0 = buckling.diagram_y.c_m0(n_ed / ncr_y);
+        let c_mz = buckling.diagram_z.c_m0(n_ed / ncr_z);
+        let lambda_0_lim =
+            0.2 * c_1.sqrt() * ((1.0 - n_ed / ncr_z) * (1.0 - n_ed / ncr_tf)).powf(0.25);
+        let (c_my, c_mlt) = if lambda_0 <= lambda_0_lim {
+            (c_my0, 1.0)
+        } else {
+            // sqrt(eps_y) a_LT / (1 + sqrt(eps_y) a_LT) with eps_y = M_y A / (N W_el,y)
+            let root = a_lt * (m_y * area).sqrt();
+            let share = if root > 0.0 {
+                root / (root + (n_ed * self.crs.w_el(Axis::Y)).sqrt())
+            } else {
+                0.0
+            };
+            let c_my = (1.0 - c_my0).mul_add(share, c_my0);
+            let c_mlt = (c_my.powi(2) * a_lt
+                / ((1.0 - n_ed / ncr_z) * (1.0 - n_ed / ncr_t)).sqrt())
+            .max(1.0);
+            (c_my, c_mlt)
+        };
+
+        let m_pl_y = self.crs.w_pl(Axis::Y) * fy / g1;
+        let m_pl_z = self.crs.w_pl(Axis::Z) * fy / g1;
+        let m_y = m_y / (khi_lt * m_pl_y);
+        let m_z = m_z / m_pl_z;
+        let lambda_z4 = lambda_z.powi(4);
+        let b_lt = 0.5 * a_lt * lambda_0.powi(2) * m_y * m_z;
+        let c_lt = 10.0 * a_lt * lambda_0.powi(2) / (5.0 + lambda_z4) * m_y / c_my;
+        let d_lt = 2.0 * a_lt * lambda_0 / (0.1 + lambda_z4) * m_y / c_my * m_z / c_mz;
+        let e_lt = 1.7 * a_lt * lambda_0 / (0.1 + lambda_z4) * m_y / c_my;
+
+        let w_el = |axis| self.crs.w_el(axis) / self.crs.w_pl(axis);
+        let direct = |w: f64, c_m: f64, lt: f64| {
+            let lambda = 1.6 / w * c_m.powi(2) * lambda_max;
+            (w - 1.0).mul_add((2.0 - lambda - lambda * lambda_max).mul_add(n_pl, -lt), 1.0)
+        };
+        let cross = |w: f64, c_m: f64, lt: f64| {
+            let lambda = 14.0 * c_m.powi(2) * lambda_max.powi(2) / w.powi(5);
+            (w - 1.0).mul_add((2.0 - lambda).mul_add(n_pl, -lt), 1.0)
+        };
+        let c_yy = direct(w_y, c_my, b_lt).max(w_el(Axis::Y));
+        let c_yz = cross(w_z, c_mz, c_lt).max(0.6 * (w_z / w_y).sqrt() * w_el(Axis::Z));
+        let c_zy = cross(w_y, c_my, d_lt).max(0.6 * (w_y / w_z).sqrt() * w_el(Axis::Y));
+        let c_zz = direct(w_z, c_mz, e_lt).max(w_el(Axis::Z));
+
+        let amplify_y = c_my * c_mlt / (1.0 - n_ed / ncr_y);
+        let amplify_z = c_mz / (1.0 - n_ed / ncr_z);
+        (
+            amplify_y * mu_y / c_yy,
+            amplify_z * mu_y / c_yz * 0.6 * (w_z / w_y).sqrt(),
+            amplify_y * mu_z / c_zy * 0.6 * (w_y / w_z).sqrt(),
+            amplify_z * mu_z / c_zz,
+        )
+    }
+
+    /*------------- FIRE, NS-EN 1993-1-2 4.2.3 -------------*/
+    // Resistances at the steel temperature `theta` in degrees Celsius, using the partial
+    // factors of the accidental design situation and plastic moduli for class 1 and 2
+
+    /// Tension resistance, 4.2.3.1
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_fi(&self, theta: f64) -> f64 {
+        k_y_theta(theta) * self.N_pl(&LimitStateType::A)
+    }
+    /// Slenderness for flexural buckling at elevated temperature, 4.7
+    #[must_use]
+    pub fn lambda_fi(&self, theta: f64, lk: f64, axis: Axis) -> f64 {
+        let lambda = (self.N_pl(&LimitStateType::K) / self.euler_load(lk, axis)).sqrt();
+        f_4_7(lambda, theta)
+    }
+    /// Flexural buckling resistance with the fire buckling curve, 4.2.3.2
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_b_fi(&self, theta: f64, lk: f64, axis: Axis) -> f64 {
+        let khi = f_4_6(
+            self.lambda_fi(theta, lk, axis),
+            alpha(self.mat.f_y(&LimitStateType::K)),
+        );
+        khi * self.N_fi(theta)
+    }
+    /// Moment resistance, 4.2.3.3 with a uniform temperature
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_fi(&self, theta: f64, axis: Axis) -> f64 {
+        k_y_theta(theta) * self.M_pl(axis, &LimitStateType::A)
+    }
+    /// Lateral torsional buckling resistance about the y-axis for the elastic critical moment
+    /// `m_cr`, 4.2.3.3
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_b_fi(&self, theta: f64, m_cr: f64) -> f64 {
+        let lambda = (self.M_pl(Axis::Y, &LimitStateType::K) / m_cr).sqrt();
+        let khi = f_4_6(
+            f_4_7(lambda, theta),
+            alpha(self.mat.f_y(&LimitStateType::K)),
+        );
+        khi * self.M_fi(theta, Axis::Y)
+    }
+
+    /// Utilisation in the fire design situation, 4.2.3.5 for compression with the buckling
+    /// lengths `lk_y` and `lk_z` and a uniform moment distribution, and a linear interaction
+    /// for tension. Lateral torsional buckling is checked when `m_cr` is given
+    #[allow(clippy::similar_names)]
+    #[must_use]
+    pub fn fire_utilisation(
+        &self,
+        theta: f64,
+        load_case: &LoadCase,
+        lk_y: f64,
+        lk_z: f64,
+        m_cr: Option<f64>,
+    ) -> f64 {
+        let (my, mz) = (load_case.My.abs(), load_case.Mz.abs());
+        let (m_y_rd, m_z_rd) = (self.M_fi(theta, Axis::Y), self.M_fi(theta, Axis::Z));
+        let m_b_rd = m_cr.map_or(m_y_rd, |m_cr| self.M_b_fi(theta, m_cr));
+        if load_case.N >= 0.0 {
+            return load_case.N / self.N_fi(theta) + my / m_b_rd + mz / m_z_rd;
+        }
+        let n_ed = -load_case.N;
+        let (n_b_y, n_b_z) = (
+            self.N_b_fi(theta, lk_y, Axis::Y),
+            self.N_b_fi(theta, lk_z, Axis::Z),
+        );
+        let lambda_y = self.lambda_fi(theta, lk_y, Axis::Y);
+        let lambda_z = self.lambda_fi(theta, lk_z, Axis::Z);
+        let k_y = k_y_fi(
+            beta_m(1.0),
+            self.lambda_fi(20.0, lk_y, Axis::Y),
+            lambda_y,
+            n_ed,
+            n_b_y,
+        );
+        let k_z = k_z_fi(
+            beta_m(1.0),
+            self.lambda_fi(20.0, lk_z, Axis::Z),
+            lambda_z,
+            n_ed,
+            n_b_z,
+        );
+        // 4.21a
+        let flexural = n_ed / n_b_y.min(n_b_z) + k_y * my / m_y_rd + k_z * mz / m_z_rd;
+        // 4.21b
+        let torsional = m_cr.map_or(0.0, |_| {
+            let k_lt = k_lt_fi(beta_m(1.0), lambda_z, n_ed, n_b_z);
+            n_ed / n_b_z + k_lt * my / m_b_rd + k_z * mz / m_z_rd
+        });
+        flexural.max(torsional)
+    }
+
+    /// Critical temperature, or `None` if the member fails before it is heated
+    ///
+    /// 4.22 with the degree of utilisation at time t = 0 is only used where stability need not
+    /// be considered, 4.2.4(1), that is for tension without lateral torsional buckling. Otherwise
+    /// the temperature where [`ColumnBeam::fire_utilisation`] reaches unity is found by bisection
+    #[must_use]
+    pub fn critical_temperature(
+        &self,
+        load_case: &LoadCase,
+        lk_y: f64,
+        lk_z: f64,
+        m_cr: Option<f64>,
+    ) -> Option<f64> {
+        let utilisation = |theta| self.fire_utilisation(theta, load_case, lk_y, lk_z, m_cr);
+        let mu0 = utilisation(20.0);
+        if mu0 >= 1.0 {
+            return None;
+        }
+        if load_case.N >= 0.0 && m_cr.is_none() {
+            return Some(f_4_22(mu0.max(0.013)));
+        }
+        let (mut low, mut high) = (20.0, 1200.0);
+        for _ in 0..60 {
+            let mid = 0.5 * (low + high);
+            if utilisation(mid) < 1.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some(0.5 * (low + high))
+    }
 
     #[must_use]
     pub fn json(&self) -> Value {
@@ -90,6 +1149,7 @@ impl ColumnBeam {
             "EA": self.EA(),
             "EI_y": self.EI(Axis::Y),
             "EI_z": self.EI(Axis::Z),
+            "GI_t": self.GI_t(),
             "N_pl_k": self.N_pl( &LimitStateType::K),
             "V_pl_y_k": self.V_pl(Axis::Y, &LimitStateType::K),
             "M_el_y_k":  self.M_el(Axis::Y,&LimitStateType::K),
@@ -110,9 +1170,39 @@ impl ColumnBeam {
     }
 }
 
+/// Axis along which the shear force acts in the plane of bending about `axis`
+const fn shear_axis(axis: Axis) -> Axis {
+    match axis {
+        Axis::X => Axis::X,
+        Axis::Y => Axis::Z,
+        Axis::Z => Axis::Y,
+    }
+}
+const fn moment(load_case: &LoadCase, axis: Axis) -> f64 {
+    match axis {
+        Axis::X => load_case.Mx,
+        Axis::Y => load_case.My,
+        Axis::Z => load_case.Mz,
+    }
+    .abs()
+}
+const fn shear(load_case: &LoadCase, axis: Axis) -> f64 {
+    match axis {
+        Axis::X => 0.0,
+        Axis::Y => load_case.Vy,
+        Axis::Z => load_case.Vz,
+    }
+    .abs()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crs::ibeam::IBeam;
+    use crate::crs::standard::PresetCrs;
+    use crate::crs::tee::Tee;
+    use crate::mat::steel::Variant;
+    use crate::mmb::buckling::EulerCase;
     use crate::{crs::circle::Circle, zequality::Zeq};
 
     #[test]
@@ -146,9 +1236,437 @@ mod tests {
         assert_zeq!(mmb.EI(Axis::Y), 1_750_000_000_000.0);
     }
     #[test]
+    fn torsional_stiffness() {
+        let mmb = heb();
+        assert_zeq!(mmb.GI_t(), 210_000.0 / 2.6 * 1_860_000.0);
+        assert_zeq!(mmb.json()["GI_t"].as_f64().unwrap_or_default(), mmb.GI_t());
+    }
+    #[test]
+    fn annex_sets_partial_factors() {
+        let mmb = ColumnBeam::default().with_annex(NationalAnnex::Recommended);
+        assert_eq!(mmb.annex, NationalAnnex::Recommended);
+        assert_zeq!(mmb.N_pl(&LimitStateType::D), 3_550_000.0);
+
+        let lk = 10000.0;
+        let ns = ColumnBeam::default();
+        let ds = ColumnBeam::default().with_annex(NationalAnnex::DS);
+        let ratio = ns.buckle_cap(lk, Axis::Z, &BuckleCurve::C, &LimitStateType::D)
+            / ds.buckle_cap(lk, Axis::Z, &BuckleCurve::C, &LimitStateType::D);
+        assert_zeq!(ratio, 1.20 / 1.05);
+    }
+    #[test]
+    fn round_trips_through_json_and_ron() {
+        let mmb = ColumnBeam {
+            crs: Box::new(Circle::default()),
+            ..Default::default()
+        };
+
+        let text = serde_json::to_string(&mmb).expect("Couldnt serialise");
+        let back: ColumnBeam = serde_json::from_str(&text).expect(&text);
+        assert_eq!(serde_json::to_string(&back).expect(&text), text);
+        assert_zeq!(back.EA(), mmb.EA());
+
+        let text = ron::to_string(&mmb).expect("Couldnt serialise");
+        let back: ColumnBeam = ron::from_str(&text).expect(&text);
+        assert_eq!(ron::to_string(&back).expect(&text), text);
+        assert_zeq!(back.N_pl(&LimitStateType::D), mmb.N_pl(&LimitStateType::D));
+    }
+    #[test]
     fn euler_load() {
         let mmb = ColumnBeam::default();
         let lk = 10000.0;
         assert_zeq!(mmb.euler_load(lk, Axis::Z), 172_718.077_019);
     }
+
+    fn heb() -> ColumnBeam {
+        let crs = PresetCrs::from_name("HEB 300").expect("Couldnt create crs");
+        ColumnBeam::new(Box::new(crs), Steel::default())
+    }
+    #[test]
+    fn buckling_curve_is_chosen_from_the_section() {
+        let mmb = heb();
+        assert_eq!(mmb.buckle_curve(Axis::Y), BuckleCurve::B);
+        assert_eq!(mmb.buckle_curve(Axis::Z), BuckleCurve::C);
+        let lk = 6000.0;
+        assert_zeq!(
+            mmb.buckle_cap_auto(lk, Axis::Z, &LimitStateType::D),
+            mmb.buckle_cap(lk, Axis::Z, &BuckleCurve::C, &LimitStateType::D)
+        );
+        let welded = heb().with_fabrication(Fabrication::Welded);
+        assert_eq!(welded.buckle_curve(Axis::Y), BuckleCurve::B);
+        let chs = ColumnBeam::new(
+            Box::new(PresetCrs::from_name("CHS 219.1x5").expect("Couldnt create crs")),
+            Steel::default(),
+        );
+        assert_eq!(chs.buckle_curve(Axis::Y), BuckleCurve::A);
+        let cold = chs.with_fabrication(Fabrication::ColdFormed);
+        assert_eq!(cold.buckle_curve(Axis::Z), BuckleCurve::C);
+        // S460 keeps its curves with the yield strength reduced for 50 mm flanges
+        let crs = IBeam::new(500.0, 300.0, 30.0, 50.0, 27.0);
+        let steel = Steel::for_crs(&Variant::S460N, &crs).expect("Couldnt create steel");
+        assert_zeq!(steel.f_y(&LimitStateType::K), 430.0);
+        let s460 = ColumnBeam::new(Box::new(crs), steel);
+        assert_eq!(s460.buckle_curve(Axis::Z), BuckleCurve::A);
+    }
+    #[test]
+    fn lateral_torsional_buckling() {
+        let mmb = heb();
+        let ls = LimitStateType::D;
+        let udl = MomentDiagram::UniformLoad;
+        let m_cr = mmb.M_cr(6000.0, &udl, 1.0, 1.0, 0.0);
+        assert_zeq!(m_cr, 1_249_980_541.750_224);
+        // Loads on the top flange are destabilising
+        assert_zeq!(mmb.M_cr(6000.0, &udl, 1.0, 1.0, 150.0), 923_565_896.787_409);
+        assert_zeq!(mmb.lambda_lt(m_cr), 0.728_758);
+        assert_zeq!(mmb.M_b(m_cr, None, &ls), 527_175_677.643_347);
+        assert_zeq!(mmb.M_b(m_cr, Some(&udl), &ls), 557_145_482.804_603);
+
+        let load = LoadCase::new().moment_kNm(0.0, 400.0, 0.0);
+        let check = mmb.ltb_check(&load, m_cr, Some(&udl), &ls);
+        assert_eq!(check.clause, "6.3.2.1");
+        assert_zeq!(check.utilisation, 0.717_945);
+        let small = LoadCase::new().moment_kNm(0.0, 100.0, 0.0);
+        assert_eq!(
+            mmb.ltb_check(&small, m_cr, Some(&udl), &ls).clause,
+            "6.3.2.2(4)"
+        );
+    }
+    #[test]
+    fn bending_and_axial_compression() {
+        let mmb = heb();
+        let ls = LimitStateType::D;
+        let udl = MomentDiagram::UniformLoad;
+        let buckling = MemberBuckling::from_lengths(
+            &BucklingLength::from_case(6000.0, EulerCase::Pinned),
+            Some(mmb.M_cr(6000.0, &udl, 1.0, 1.0, 0.0)),
+            udl,
+            MomentDiagram::EndMoments { psi: 1.0 },
+        );
+        assert_zeq!(mmb.khi(6000.0, Axis::Y, None), 0.834_962);
+        assert_zeq!(mmb.khi(6000.0, Axis::Z, None), 0.519_333);
+        let load = LoadCase::new()
+            .axial_kN(-1000.0)
+            .moment_kNm(0.0, 200.0, 20.0);
+        let [y, z] = mmb.member_checks(&load, &buckling, InteractionMethod::AnnexA, &ls);
+        assert_eq!(y.clause, "6.3.3 (6.61)");
+        assert_eq!(z.clause, "6.3.3 (6.62)");
+        assert_zeq!(y.utilisation, 0.770_251);
+        assert_zeq!(z.utilisation, 0.732_315);
+        let [y, z] = mmb.member_checks(&load, &buckling, InteractionMethod::AnnexB, &ls);
+        assert_zeq!(y.utilisation, 0.674_091);
+        assert_zeq!(z.utilisation, 0.825_864);
+        // Without torsional deformations lateral torsional buckling does not reduce the moment
+        let restrained = MemberBuckling {
+            m_cr: None,
+            ..buckling
+        };
+        let [y, z] = mmb.member_checks(&load, &restrained, InteractionMethod::AnnexA, &ls);
+        assert_zeq!(y.utilisation, 0.644_742);
+        assert_zeq!(z.utilisation, 0.650_971);
+    }
+    #[test]
+    fn torsional_buckling() {
+        let mmb = heb();
+        let ls = LimitStateType::D;
+        assert_zeq!(mmb.torsional_load(6000.0), 10_929_323.335_643);
+        // Doubly symmetric sections do not couple torsion with flexure
+        assert_zeq!(
+            mmb.torsional_flexural_load(6000.0, 6000.0, 6000.0),
+            mmb.torsional_load(6000.0)
+        );
+        assert!(
+            mmb.torsional_buckle_cap(6000.0, 6000.0, 6000.0, &ls)
+                > mmb.N_b(6000.0, 6000.0, 6000.0, &ls)
+        );
+        assert_zeq!(
+            mmb.N_b(6000.0, 6000.0, 6000.0, &ls),
+            mmb.buckle_cap_auto(6000.0, Axis::Z, &ls)
+        );
+        // A long torsional buckling length makes torsion govern
+        assert_zeq!(
+            mmb.N_b(2000.0, 2000.0, 60_000.0, &ls),
+            mmb.torsional_buckle_cap(2000.0, 2000.0, 60_000.0, &ls)
+        );
+    }
+    #[test]
+    fn torsional_flexural_buckling_of_tees() {
+        let tee = ColumnBeam::new(Box::new(Tee::default()), Steel::default());
+        let ls = LimitStateType::D;
+        assert_zeq!(tee.torsional_load(3000.0), 2_552_031.999_385);
+        // The offset shear centre couples torsion with lateral flexure about z
+        assert_zeq!(
+            tee.torsional_flexural_load(3000.0, 3000.0, 3000.0),
+            710_714.530_797
+        );
+        assert!(
+            tee.torsional_flexural_load(3000.0, 3000.0, 3000.0) < tee.euler_load(3000.0, Axis::Z)
+        );
+        assert_zeq!(
+            tee.N_b(3000.0, 3000.0, 3000.0, &ls),
+            tee.torsional_buckle_cap(3000.0, 3000.0, 3000.0, &ls)
+        );
+        assert_zeq!(tee.N_b(3000.0, 3000.0, 3000.0, &ls), 436_505.094_640);
+    }
+    #[test]
+    fn shear_buckling_of_plate_girders() {
+        let girder = ColumnBeam::new(
+            Box::new(IBeam::new(1500.0, 400.0, 10.0, 25.0, 0.0)),
+            Steel::default(),
+        )
+        .with_fabrication(Fabrication::Welded);
+        let ls = LimitStateType::D;
+        let rigid = WebPanel {
+            a: 3000.0,
+            end_post: EndPost::Rigid,
+        };
+        let non_rigid = WebPanel {
+            end_post: EndPost::NonRigid,
+            ..rigid
+        };
+        assert!(girder.shear_buckling());
+        // The web area is increased by eta, 6.2.6(3)(a)
+        assert_zeq!(
+            girder.V_pl(Axis::Z, &LimitStateType::K),
+            1.2 * 1450.0 * 10.0 * 355.0 / 3f64.sqrt()
+        );
+        assert_zeq!(girder.lambda_w(&rigid).expect("I-section"), 1.901_653);
+        assert_zeq!(
+            girder.V_bw(&rigid, &ls).expect("I-section"),
+            1_490_450.585_333
+        );
+        assert_zeq!(
+            girder.V_bw(&non_rigid, &ls).expect("I-section"),
+            1_235_359.017_810
+        );
+        assert_zeq!(
+            girder.M_f(0.0, &ls).expect("I-section"),
+            4_986_904_761.904_761
+        );
+
+        let load = LoadCase::new()
+            .moment_kNm(0.0, 2000.0, 0.0)
+            .shear_kN(0.0, 1500.0);
+        let check = girder
+            .shear_buckling_check(&load, &rigid, &ls)
+            .expect("I-section");
+        assert_eq!(check.clause, "NS-EN 1993-1-5 5.5");
+        assert_zeq!(check.utilisation, 0.950_369);
+        // Moments the flanges carry alone need no interaction
+        let check = girder
+            .bending_shear_buckling_check(&load, &rigid, &ls)
+            .expect("I-section");
+        assert_zeq!(check.utilisation, 0.295_682);
+        let load = LoadCase::new()
+            .moment_kNm(0.0, 5500.0, 0.0)
+            .shear_kN(0.0, 1000.0);
+        assert_zeq!(
+            girder
+                .shear_buckling_check(&load, &rigid, &ls)
+                .expect("I-section")
+                .utilisation,
+            0.670_938
+        );
+        assert_zeq!(
+            girder
+                .bending_shear_buckling_check(&load, &rigid, &ls)
+                .expect("I-section")
+                .utilisation,
+            0.843_834
+        );
+        assert!(ColumnBeam::default().V_b(&rigid, 0.0, 0.0, &ls).is_none());
+    }
+    #[test]
+    fn resistance_to_transverse_forces() {
+        let girder = ColumnBeam::new(
+            Box::new(IBeam::new(1500.0, 400.0, 10.0, 25.0, 0.0)),
+            Steel::default(),
+        );
+        let ls = LimitStateType::D;
+        let panel = WebPanel {
+            a: 3000.0,
+            end_post: EndPost::NonRigid,
+        };
+        let patch = Patch {
+            force: 500_000.0,
+            s_s: 200.0,
+            load: TransverseLoad::A,
+        };
+        assert_zeq!(
+            girder.F_Rd(&patch, &panel, &ls).expect("I-section"),
+            721_850.790_443
+        );
+        let check = girder
+            .patch_loading_check(&patch, &panel, &ls)
+            .expect("I-section");
+        assert_eq!(check.clause, "NS-EN 1993-1-5 6.6");
+        assert_zeq!(check.utilisation, 0.692_664);
+        let load = LoadCase::new().moment_kNm(0.0, 2000.0, 0.0);
+        let check = girder
+            .bending_patch_loading_check(&load, &patch, &panel, &ls)
+            .expect("I-section");
+        assert_eq!(check.clause, "NS-EN 1993-1-5 7.2");
+        assert_zeq!(check.utilisation, 0.683_677);
+        // Near an unstiffened end
+        let end = Patch {
+            s_s: 100.0,
+            load: TransverseLoad::C { c: 100.0 },
+            ..patch
+        };
+        assert_zeq!(
+            girder.F_Rd(&end, &panel, &ls).expect("I-section"),
+            297_469.600_607
+        );
+        // Stocky webs of rolled sections do not buckle
+        let heb = ColumnBeam::new(Box::new(IBeam::default()), Steel::default());
+        let long = WebPanel { a: 1.0e9, ..panel };
+        let support = Patch {
+            s_s: 100.0,
+            ..patch
+        };
+        assert_zeq!(
+            heb.F_Rd(&support, &long, &ls).expect("I-section"),
+            1_251_268.096_208
+        );
+    }
+    #[test]
+    fn tension_is_limited_by_net_section() {
+        let mmb = heb();
+        let load = LoadCase::new().axial_kN(1000.0);
+        assert_zeq!(mmb.N_t(&LimitStateType::D), mmb.N_c(&LimitStateType::D));
+        let mmb = mmb.with_net_area(12_000.0);
+        assert_zeq!(mmb.N_t(&LimitStateType::D), 4_406_400.0);
+        let check = mmb.tension_check(&load, &LimitStateType::D);
+        assert_eq!(check.clause, "6.2.3");
+        assert_zeq!(check.utilisation, 0.226_943);
+        assert_zeq!(
+            mmb.compression_check(&load, &LimitStateType::D).utilisation,
+            0.0
+        );
+    }
+    #[test]
+    fn cross_section_checks_cover_6_2() {
+        let mmb = heb();
+        let load = LoadCase::new()
+            .axial_kN(-500.0)
+            .moment_kNm(5.0, 300.0, 0.0)
+            .shear_kN(0.0, 400.0);
+        let ls = LimitStateType::D;
+        assert_zeq!(mmb.shear_check(&load, Axis::Z, &ls).utilisation, 0.486_705);
+        assert_eq!(mmb.shear_check(&load, Axis::Z, &ls).clause, "6.2.7(9)");
+        assert_zeq!(mmb.torsion_check(&load, &ls).utilisation, 0.261_669);
+        assert_zeq!(
+            mmb.bending_check(&load, Axis::Y, &ls).utilisation,
+            0.474_510
+        );
+        assert_zeq!(
+            mmb.bending_shear_check(&load, Axis::Y, &ls).utilisation,
+            0.474_510
+        );
+        // Shear above half the plastic resistance reduces the moment resistance by 6.2.8
+        let sheared = LoadCase::new()
+            .axial_kN(-500.0)
+            .moment_kNm(5.0, 300.0, 0.0)
+            .shear_kN(0.0, 600.0);
+        assert_zeq!(
+            mmb.shear_check(&sheared, Axis::Z, &ls).utilisation,
+            0.730_057
+        );
+        assert_zeq!(
+            mmb.bending_shear_check(&sheared, Axis::Y, &ls).utilisation,
+            0.484_867
+        );
+        // Scales with the load like the other checks, above bending and shear alone
+        assert_zeq!(mmb.bending_axial_check(&load, &ls).utilisation, 0.560_221);
+
+        let checks = mmb.cross_section_checks(&load, &ls);
+        assert_eq!(checks.len(), 10);
+        let governing = Check::governing(&checks).expect("No checks");
+        assert_eq!(governing.clause, "6.2.9");
+        assert!(governing.passes());
+        assert!(!mmb.shear_buckling());
+    }
+    #[test]
+    fn axial_force_reduces_plastic_moments() {
+        let mmb = heb();
+        let ls = LimitStateType::D;
+        // Below the limits of 6.33 to 6.35
+        assert_zeq!(mmb.M_N(Axis::Y, -400e3, &ls), mmb.M_c(Axis::Y, &ls));
+        assert_zeq!(mmb.M_N(Axis::Y, -2000e3, &ls), 431_965_598.406_663);
+        assert_zeq!(mmb.M_N(Axis::Z, -2000e3, &ls), 281_260_112.129_636);
+        let load = LoadCase::new()
+            .axial_kN(-2000.0)
+            .moment_kNm(0.0, 300.0, 50.0);
+        let check = mmb.bending_axial_check(&load, &ls);
+        assert_eq!(check.clause, "6.2.9");
+        assert_zeq!(check.utilisation, 0.821_995);
+        assert_zeq!(mmb.f_6_41(&load.scaled(1.0 / check.utilisation), &ls), 1.0);
+        let linear =
+            2000e3 / mmb.N_c(&ls) + 300e6 / mmb.M_c(Axis::Y, &ls) + 50e6 / mmb.M_c(Axis::Z, &ls);
+        assert!(check.utilisation < linear);
+    }
+    #[test]
+    fn checks_work_for_solid_and_hollow_sections() {
+        let load = LoadCase::new()
+            .moment_kNm(1.0, 10.0, 5.0)
+            .shear_kN(50.0, 100.0);
+        for crs in [
+            Box::new(Rect::default()) as Box<dyn CrossSection>,
+            Box::new(Circle::default()),
+            Box::new(crate::crs::tube::Tube::default()),
+            Box::new(crate::crs::r#box::Box::default()),
+        ] {
+            let mmb = ColumnBeam::new(crs, Steel::default());
+            let checks = mmb.cross_section_checks(&load, &LimitStateType::D);
+            assert!(checks.iter().all(|check| check.utilisation.is_finite()));
+        }
+    }
+    #[test]
+    fn fire_resistances_use_accidental_factors() {
+        let mmb = heb();
+        assert_zeq!(mmb.N_fi(550.0), 3_305_937.5);
+        assert_zeq!(mmb.N_fi(20.0), mmb.N_pl(&LimitStateType::K));
+        assert_zeq!(mmb.N_b_fi(550.0, 4000.0, Axis::Y), 2_540_605.372_072);
+        assert_zeq!(mmb.N_b_fi(550.0, 4000.0, Axis::Z), 1_947_776.033_673);
+        assert_zeq!(mmb.M_fi(550.0, Axis::Y), 414_906_250.0);
+        assert_zeq!(mmb.M_b_fi(550.0, 2000e6), 274_524_417.603_740);
+    }
+    #[test]
+    fn fire_utilisation_and_critical_temperature() {
+        let mmb = heb();
+        let tension = LoadCase::new().axial_kN(500.0).moment_kNm(0.0, 50.0, 0.0);
+        assert_zeq!(
+            mmb.fire_utilisation(550.0, &tension, 4000.0, 4000.0, None),
+            0.271_752
+        );
+        let load = LoadCase::new()
+            .axial_kN(-1000.0)
+            .moment_kNm(0.0, 100.0, 0.0);
+        assert_zeq!(
+            mmb.fire_utilisation(550.0, &load, 4000.0, 4000.0, None),
+            0.811_255
+        );
+        assert_zeq!(
+            mmb.fire_utilisation(550.0, &load, 4000.0, 4000.0, Some(2000e6)),
+            0.877_672
+        );
+        let theta = mmb
+            .critical_temperature(&load, 4000.0, 4000.0, Some(2000e6))
+            .expect("Member should resist the load cold");
+        assert_zeq!(theta, 572.491_771);
+        assert_zeq!(
+            mmb.fire_utilisation(theta, &load, 4000.0, 4000.0, Some(2000e6)),
+            1.0
+        );
+        // Without stability the critical temperature follows 4.22
+        let mu0 = mmb.fire_utilisation(20.0, &tension, 4000.0, 4000.0, None);
+        assert_zeq!(
+            mmb.critical_temperature(&tension, 4000.0, 4000.0, None)
+                .expect("Member should resist the load cold"),
+            f_4_22(mu0)
+        );
+        let heavy = LoadCase::new().axial_kN(-6000.0);
+        assert!(mmb
+            .critical_temperature(&heavy, 4000.0, 4000.0, None)
+            .is_none());
+    }
 }
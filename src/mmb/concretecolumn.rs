@@ -0,0 +1,265 @@
+use crate::crs::reinforced::{Outline, Reinforced};
+use crate::load::loadcase::LoadCase;
+use crate::mat::{concrete::Concrete, reinforcement::Reinforcement};
+use crate::Axis;
+use serde::{Deserialize, Serialize};
+
+/// Number of strips the concrete is divided into when integrating stresses
+const STRIPS: usize = 200;
+
+/// Reinforced concrete section checked in the ultimate limit state according to
+/// NS-EN 1992-1-1 6.1. Axial forces are positive in tension, as in `LoadCase`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConcreteColumn {
+    pub crs: Reinforced,
+    pub concrete: Concrete,
+    pub rebar: Reinforcement,
+}
+
+impl ConcreteColumn {
+    #[must_use]
+    pub const fn new(crs: Reinforced, concrete: Concrete, rebar: Reinforcement) -> Self {
+        Self {
+            crs,
+            concrete,
+            rebar,
+        }
+    }
+
+    /// Axial force and moment about `axis` from a linear strain field, positive in
+    /// compression, given by the strains at the top (z = h/2) and bottom (z = -h/2)
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn forces(&self, eps_top: f64, eps_bottom: f64, axis: Axis) -> (f64, f64) {
+        let h = self.crs.depth(axis);
+        let strain = |z: f64| (eps_top - eps_bottom).mul_add(z / h + 0.5, eps_bottom);
+        let dz = h / STRIPS as f64;
+
+        let (mut n, mut m) = (0.0, 0.0);
+        for i in 0..STRIPS {
+            let z = (i as f64 + 0.5).mul_add(dz, -h / 2.0);
+            let force = self.concrete.stress(strain(z)) * self.crs.width_at(z, axis) * dz;
+            n += force;
+            m += force * z;
+        }
+        for bar in &self.crs.bars {
+            let z = Reinforced::lever(bar, axis);
+            // The concrete displaced by the bar is already included above
+            let stress = self.rebar.stress(strain(z)) - self.concrete.stress(strain(z));
+            let force = stress * bar.area();
+            n += force;
+            m += force * z;
+        }
+        (-n, m)
+    }
+
+    /// Strains at the top and bottom for the failure states of 6.1(5) and Figure 6.1,
+    /// running from pure tension at `t` = 0 to pure compression at `t` = 1. With `positive`
+    /// the top is compressed, otherwise the bottom.
+    #[allow(clippy::similar_names)]
+    fn failure_strains(&self, t: f64, positive: bool, axis: Axis) -> (f64, f64) {
+        let h = self.crs.depth(axis);
+        let side = if positive { 1.0 } else { -1.0 };
+        let (eps_c2, eps_cu2, eps_ud) = (
+            self.concrete.eps_c2(),
+            self.concrete.eps_cu2(),
+            self.rebar.eps_ud(),
+        );
+        // Depth of the outermost bar on the tension side measured from the compressed face
+        let d = self
+            .crs
+            .bars
+            .iter()
+            .map(|bar| h / 2.0 - side * Reinforced::lever(bar, axis))
+            .fold(0.0, f64::max);
+        let d = if d > 0.0 { d } else { h };
+        let eps_far_a = eps_cu2 + (-eps_ud - eps_cu2) * h / d;
+
+        let (near, far) = if t < 1.0 / 3.0 {
+            // Pivot A: the reinforcement reaches the strain limit
+            let near = (3.0 * t).mul_add(eps_cu2 + eps_ud, -eps_ud);
+            (near, near + (-eps_ud - near) * h / d)
+        } else if t < 2.0 / 3.0 {
+            // Pivot B: the concrete reaches its ultimate strain
+            (
+                eps_cu2,
+                3.0f64.mul_add(t, -1.0).mul_add(-eps_far_a, eps_far_a),
+            )
+        } else {
+            // Pivot C: the strain is eps_c2 at (1 - eps_c2 / eps_cu2) h from the compressed face
+            let far = 3.0f64.mul_add(t, -2.0) * eps_c2;
+            let k = 1.0 - eps_c2 / eps_cu2;
+            (k.mul_add(-far, eps_c2) / (1.0 - k), far)
+        };
+        if positive {
+            (near, far)
+        } else {
+            (far, near)
+        }
+    }
+
+    /// Points (N, M) on the interaction diagram for moments about `axis`, with `points` states
+    /// for each sign of the moment
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn interaction(&self, points: usize, axis: Axis) -> Vec<(f64, f64)> {
+        let steps = points.max(2) - 1;
+        let positive = (0..=steps).map(|i| (i as f64 / steps as f64, true));
+        let negative = (0..=steps).rev().map(|i| (i as f64 / steps as f64, false));
+        positive
+            .chain(negative)
+            .map(|(t, side)| {
+                let (top, bottom) = self.failure_strains(t, side, axis);
+                self.forces(top, bottom, axis)
+            })
+            .collect()
+    }
+
+    /// Design resistance to centric compression, as a negative axial force
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_Rd_c(&self) -> f64 {
+        let eps = self.concrete.eps_c2();
+        self.forces(eps, eps, Axis::Y).0
+    }
+    /// Design resistance to centric tension
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn N_Rd_t(&self) -> f64 {
+        let eps = -self.rebar.eps_ud();
+        self.forces(eps, eps, Axis::Y).0
+    }
+
+    /// Design moment resistance about `axis` with the axial force `n_ed`, for moments
+    /// compressing the top when `positive`
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub fn M_Rd(&self, n_ed: f64, positive: bool, axis: Axis) -> f64 {
+        if n_ed < self.N_Rd_c() || n_ed > self.N_Rd_t() {
+            return 0.0;
+        }
+        // The axial force falls monotonically from pure tension to pure compression
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..60 {
+            let t = 0.5 * (low + high);
+            let (top, bottom) = self.failure_strains(t, positive, axis);
+            if self.forces(top, bottom, axis).0 > n_ed {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+        let (top, bottom) = self.failure_strains(0.5 * (low + high), positive, axis);
+        self.forces(top, bottom, axis).1.abs()
+    }
+
+    /// Utilisation of the load case, using 5.8.9(4) for biaxial bending and never less than the
+    /// ratio of the axial force to the centric resistance
+    #[must_use]
+    pub fn utilisation(&self, load: &LoadCase) -> f64 {
+        let axial = if load.N < 0.0 {
+            load.N / self.N_Rd_c()
+        } else {
+            load.N / self.N_Rd_t()
+        };
+        if axial > 1.0 {
+            return axial;
+        }
+        let ratio = |moment: f64, axis: Axis| {
+            if moment == 0.0 {
+                0.0
+            } else {
+                moment.abs() / self.M_Rd(load.N, moment > 0.0, axis)
+            }
+        };
+        let ratio_y = ratio(load.My, Axis::Y);
+        let ratio_z = ratio(load.Mz, Axis::Z);
+        let a = match self.crs.outline {
+            Outline::Circle(_) => 2.0,
+            Outline::Rect(_) => {
+                let n_ratio = (load.N / self.N_Rd_c()).clamp(0.1, 1.0);
+                if n_ratio <= 0.7 {
+                    (n_ratio - 0.1).mul_add(0.5 / 0.6, 1.0)
+                } else {
+                    (n_ratio - 0.7).mul_add(0.5 / 0.3, 1.5)
+                }
+            }
+        };
+        (ratio_y.powf(a) + ratio_z.powf(a)).powf(1.0 / a).max(axial)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crs::{circle::Circle, rect::Rect};
+    use crate::zequality::Zeq;
+
+    fn column() -> ConcreteColumn {
+        ConcreteColumn::new(
+            Reinforced::rect(Rect::new(300.0, 300.0), 2, 20.0, 40.0),
+            Concrete::default(),
+            Reinforcement::default(),
+        )
+    }
+
+    #[test]
+    fn centric_capacities() {
+        // (A_c - A_s) f_cd + A_s E_s eps_c2 and A_s f_yd
+        let mmb = column();
+        assert_zeq!((mmb.N_Rd_c() / 1000.0).round(), -2011.0);
+        assert_zeq!((mmb.N_Rd_t() / 1000.0).round(), 546.0);
+    }
+
+    #[test]
+    fn bending_without_axial_force() {
+        // 2 x 314 mm^2 at d = 250 mm gives about 61 kNm ignoring the compression bars
+        let m = column().M_Rd(0.0, true, Axis::Y) / 1e6;
+        assert!(m > 60.0 && m < 64.0, "{m}");
+        assert_zeq!(m, column().M_Rd(0.0, false, Axis::Z) / 1e6);
+    }
+
+    #[test]
+    fn compression_increases_moment_capacity() {
+        let mmb = column();
+        let m_0 = mmb.M_Rd(0.0, true, Axis::Y);
+        assert!(mmb.M_Rd(-500_000.0, true, Axis::Y) > m_0);
+        assert_zeq!(mmb.M_Rd(-3_000_000.0, true, Axis::Y), 0.0);
+    }
+
+    #[test]
+    fn interaction_diagram_is_closed() {
+        let diagram = column().interaction(20, Axis::Y);
+        assert_eq!(diagram.len(), 40);
+        let (n_t, m_t) = diagram[0];
+        let (n_c, m_c) = diagram[19];
+        assert_zeq!((n_t / 1000.0).round(), 546.0);
+        assert_zeq!((n_c / 1000.0).round(), -2011.0);
+        assert_zeq!((m_t / 1e6).round(), 0.0);
+        assert_zeq!((m_c / 1e6).round(), 0.0);
+        assert!(diagram.iter().take(20).all(|(_, m)| *m >= -1e-3));
+    }
+
+    #[test]
+    fn utilisation_in_pure_compression() {
+        let mmb = column();
+        let load = LoadCase::new().axial_kN(-1000.0);
+        assert_zeq!(mmb.utilisation(&load), load.N / mmb.N_Rd_c());
+        let tension = LoadCase::new().axial_kN(273.0);
+        assert_zeq!(mmb.utilisation(&tension), tension.N / mmb.N_Rd_t());
+    }
+
+    #[test]
+    fn utilisation_of_circular_column() {
+        let mmb = ConcreteColumn::new(
+            Reinforced::circle(Circle::new(400.0), 8, 16.0, 50.0),
+            Concrete::default(),
+            Reinforcement::default(),
+        );
+        let load = LoadCase::new().axial_kN(-500.0).moment_kNm(0.0, 50.0, 0.0);
+        let m_rd = mmb.M_Rd(load.N, true, Axis::Y);
+        assert_zeq!(mmb.utilisation(&load), load.My / m_rd);
+        let biaxial = LoadCase::new().axial_kN(-500.0).moment_kNm(0.0, 50.0, 50.0);
+        assert_zeq!(mmb.utilisation(&biaxial), 2f64.sqrt() * load.My / m_rd);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::crs::CrossSection;
+use crate::err::EmsError;
+use crate::mat::stressstrain::StressStrain;
+use crate::Axis;
+
+/// Fibre of area `area` at (y, z) from the centroid
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fibre {
+    pub y: f64,
+    pub z: f64,
+    pub area: f64,
+}
+
+impl Fibre {
+    #[must_use]
+    pub const fn new(y: f64, z: f64, area: f64) -> Self {
+        Self { y, z, area }
+    }
+    /// Distance from the centroid, measured along the depth for moments about `axis`
+    #[must_use]
+    pub const fn lever(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::Y | Axis::X => self.z,
+            Axis::Z => self.y,
+        }
+    }
+}
+
+/// Cross-section discretised into fibres of a single material, for nonlinear analysis under
+/// plane sections
+///
+/// Strains, stresses and axial forces are positive in tension, and a positive curvature
+/// compresses the fibres on the positive side of the axis
+pub struct FibreSection {
+    pub fibres: Vec<Fibre>,
+    pub mat: Box<dyn StressStrain>,
+}
+
+impl FibreSection {
+    #[must_use]
+    pub fn new(fibres: Vec<Fibre>, mat: Box<dyn StressStrain>) -> Self {
+        Self { fibres, mat }
+    }
+
+    /// Divides `crs` into `layers` layers of equal thickness across the depth resisting
+    /// moments about `axis`
+    ///
+    /// # Errors
+    /// Will return an error if the shape of the cross-section is not known, as for catalogue
+    /// sections
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_crs(
+        crs: &dyn CrossSection,
+        mat: Box<dyn StressStrain>,
+        axis: Axis,
+        layers: usize,
+    ) -> Result<Self, EmsError> {
+        let depth = match axis {
+            Axis::Y | Axis::X => crs.height(),
+            Axis::Z => crs.width(),
+        };
+        let dz = depth / layers as f64;
+        let mut fibres = Vec::with_capacity(layers);
+        for i in 0..layers {
+            let lever = (i as f64 + 0.5).mul_add(dz, -depth / 2.0);
+            let width = crs.width_at(lever, axis).ok_or_else(|| {
+                EmsError::out_of_range_error(
+                    format!("The shape of {:?} is not known", crs.definition()),
+                    None,
+                )
+            })?;
+            if width > 0.0 {
+                fibres.push(match axis {
+                    Axis::Y | Axis::X => Fibre::new(0.0, lever, width * dz),
+                    Axis::Z => Fibre::new(lever, 0.0, width * dz),
+                });
+            }
+        }
+        Ok(Self::new(fibres, mat))
+    }
+
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        self.fibres.iter().map(|fibre| fibre.area).sum()
+    }
+    /// Distance from the centroid to the outermost fibre for moments about `axis`
+    #[must_use]
+    pub fn extreme_lever(&self, axis: Axis) -> f64 {
+        self.fibres
+            .iter()
+            .map(|fibre| fibre.lever(axis).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Axial force and moment about `axis` for the strain `eps_0` at the centroid and the
+    /// curvature `kappa` in 1/mm
+    #[must_use]
+    pub fn forces(&self, eps_0: f64, kappa: f64, axis: Axis) -> (f64, f64) {
+        self.fibres.iter().fold((0.0, 0.0), |(n, m), fibre| {
+            let lever = fibre.lever(axis);
+            let force = self.mat.stress(kappa.mul_add(-lever, eps_0)) * fibre.area;
+            (n + force, force.mul_add(-lever, m))
+        })
+    }
+
+    /// Centroidal strain in equilibrium with the axial force `n_ed` at the curvature `kappa`,
+    /// or `None` if the section cannot carry `n_ed`
+    #[must_use]
+    pub fn centroid_strain(&self, n_ed: f64, kappa: f64, axis: Axis) -> Option<f64> {
+        let bound = kappa
+            .abs()
+            .mul_add(self.extreme_lever(axis), self.mat.eps_u());
+        let (mut low, mut high) = (-bound, bound);
+        if self.forces(low, kappa, axis).0 > n_ed || self.forces(high, kappa, axis).0 < n_ed {
+            return None;
+        }
+        for _ in 0..100 {
+            let mid = 0.5 * (low + high);
+            if self.forces(mid, kappa, axis).0 < n_ed {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some(0.5 * (low + high))
+    }
+
+    /// Moment-curvature curve about `axis` under the constant axial force `n_ed`, as
+    /// (curvature in 1/mm, moment in Nmm) at `steps` equal increments up to `kappa_max`. The
+    /// curve ends early when the outermost fibre exceeds the limiting strain of the material
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn moment_curvature(
+        &self,
+        n_ed: f64,
+        kappa_max: f64,
+        steps: usize,
+        axis: Axis,
+    ) -> Vec<(f64, f64)> {
+        let c = self.extreme_lever(axis);
+        let mut curve = Vec::with_capacity(steps + 1);
+        for i in 0..=steps {
+            let kappa = kappa_max * i as f64 / steps as f64;
+            let Some(eps_0) = self.centroid_strain(n_ed, kappa, axis) else {
+                break;
+            };
+            if kappa.abs().mul_add(c, eps_0.abs()) > self.mat.eps_u() {
+                break;
+            }
+            curve.push((kappa, self.forces(eps_0, kappa, axis).1));
+        }
+        curve
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crs::{ibeam::IBeam, rect::Rect, standard::PresetCrs};
+    use crate::mat::stressstrain::ElasticPlastic;
+    use crate::zequality::Zeq;
+
+    fn rect() -> FibreSection {
+        FibreSection::from_crs(
+            &Rect::new(100.0, 300.0),
+            Box::new(ElasticPlastic::new(210_000.0, 355.0)),
+            Axis::Y,
+            300,
+        )
+        .expect("Couldnt discretise")
+    }
+
+    #[test]
+    fn layers_recover_section_properties() {
+        let crs = IBeam::default();
+        let section = FibreSection::from_crs(
+            &crs,
+            Box::new(ElasticPlastic::new(210_000.0, 355.0)),
+            Axis::Y,
+            300,
+        )
+        .expect("Couldnt discretise");
+        // Root fillets are neglected
+        assert_zeq!(section.extreme_lever(Axis::Y), 149.5);
+        assert_zeq!(section.area(), 14_282.0);
+        assert!(PresetCrs::from_name("HEB 300")
+            .map(|crs| FibreSection::from_crs(&crs, section.mat, Axis::Y, 10))
+            .expect("Couldnt create crs")
+            .is_err());
+    }
+
+    #[test]
+    fn elastic_and_plastic_moments() {
+        let section = rect();
+        let kappa = 1e-6;
+        let (n, m) = section.forces(0.0, kappa, Axis::Y);
+        assert_zeq!(n, 0.0);
+        assert_zeq!(m / (210_000.0 * kappa * 225e6), 0.999_988);
+
+        let curve = section.moment_curvature(0.0, 2e-4, 20, Axis::Y);
+        assert_eq!(curve.len(), 21);
+        let (_, m_end) = curve.last().copied().expect("Empty curve");
+        // M_pl (1 - (kappa_y / kappa)^2 / 3)
+        assert_zeq!(m_end / 798.75e6, 0.998_943);
+    }
+
+    #[test]
+    fn axial_force_reduces_plastic_moment() {
+        let section = rect();
+        let n_ed = -0.5 * 355.0 * 30_000.0;
+        let curve = section.moment_curvature(n_ed, 2e-4, 4, Axis::Y);
+        let (_, m_end) = curve.last().copied().expect("Empty curve");
+        assert!((m_end / (0.75 * 798.75e6) - 1.0).abs() < 5e-3);
+        assert!(section
+            .centroid_strain(-1.1 * 355.0 * 30_000.0, 0.0, Axis::Y)
+            .is_none());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::crs::CrossSection;
+use crate::erc::NSEN_1991_1_2::f_3_1;
+use crate::erc::NSEN_1993_1_2::{f_4_25, f_4_27, f_4_28, specific_heat};
+use crate::load::fire::FireCurve;
+use serde::{Deserialize, Serialize};
+
+/// Insulation encasing a steel member, NS-EN 1993-1-2 4.2.5.2
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Protection {
+    /// Thermal conductivity in W/m K
+    pub conductivity: f64,
+    /// Density in kg/m^3
+    pub density: f64,
+    /// Specific heat in J/kg K
+    pub specific_heat: f64,
+    /// Thickness in m
+    pub thickness: f64,
+}
+
+impl Protection {
+    #[must_use]
+    pub const fn new(conductivity: f64, density: f64, specific_heat: f64, thickness: f64) -> Self {
+        Self {
+            conductivity,
+            density,
+            specific_heat,
+            thickness,
+        }
+    }
+}
+
+/// Incremental steel temperature of a member exposed to fire, NS-EN 1993-1-2 4.2.5
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Heating {
+    pub curve: FireCurve,
+    /// Section factor Am/V in 1/m, also used as Ap/V for contour encased members
+    pub section_factor: f64,
+    /// Correction factor for the shadow effect, only applied to unprotected members
+    pub shadow_factor: f64,
+    pub protection: Option<Protection>,
+    /// Surface emissivity of the member, 2.2(2)
+    pub emissivity: f64,
+    /// Unit mass of steel in kg/m^3, 3.2.2(1)
+    pub density: f64,
+}
+
+impl Heating {
+    #[must_use]
+    pub fn new(curve: FireCurve, crs: &dyn CrossSection) -> Self {
+        Self {
+            curve,
+            section_factor: crs.section_factor(),
+            shadow_factor: crs.shadow_factor(),
+            protection: None,
+            emissivity: 0.7,
+            density: 7850.0,
+        }
+    }
+    #[must_use]
+    pub const fn with_protection(mut self, protection: Protection) -> Self {
+        self.protection = Some(protection);
+        self
+    }
+
+    /// Time increment in seconds, 4.2.5.1(4) and 4.2.5.2(3)
+    #[must_use]
+    pub const fn time_step(&self) -> f64 {
+        match self.protection {
+            Some(_) => 30.0,
+            None => 5.0,
+        }
+    }
+
+    /// Temperature increase of the steel at `theta_a` during one time step starting at `t`
+    /// seconds
+    fn increment(&self, t: f64, theta_a: f64) -> f64 {
+        self.protection.as_ref().map_or_else(
+            || self.unprotected(t, theta_a),
+            |p| self.protected(p, t, theta_a),
+        )
+    }
+    fn unprotected(&self, t: f64, theta_a: f64) -> f64 {
+        let theta_g = self.curve.gas_temperature(t / 60.0);
+        let h_net = f_3_1(self.curve.alpha_c(), self.emissivity, theta_g, theta_a);
+        f_4_25(
+            self.shadow_factor,
+            self.section_factor,
+            specific_heat(theta_a),
+            self.density,
+            h_net,
+            self.time_step(),
+        )
+    }
+    fn protected(&self, p: &Protection, t: f64, theta_a: f64) -> f64 {
+        let dt = self.time_step();
+        let theta_g = self.curve.gas_temperature(t / 60.0);
+        let d_theta_g = self.curve.gas_temperature((t + dt) / 60.0) - theta_g;
+        let c_a = specific_heat(theta_a);
+        let phi = f_4_28(
+            p.specific_heat,
+            p.density,
+            p.thickness,
+            self.section_factor,
+            c_a,
+            self.density,
+        );
+        f_4_27(
+            p.conductivity,
+            self.section_factor,
+            p.thickness,
+            c_a,
+            self.density,
+            phi,
+            theta_g,
+            theta_a,
+            d_theta_g,
+            dt,
+        )
+    }
+
+    /// Steel temperature at every time step up to `duration` minutes, as pairs of
+    /// (time in minutes, temperature in degrees Celsius)
+    #[allow(clippy::while_float)]
+    #[must_use]
+    pub fn history(&self, duration: f64) -> Vec<(f64, f64)> {
+        let dt = self.time_step();
+        let mut out = vec![(0.0, 20.0)];
+        let (mut t, mut theta_a) = (0.0, 20.0);
+        while t < duration * 60.0 {
+            theta_a += self.increment(t, theta_a);
+            t += dt;
+            out.push((t / 60.0, theta_a));
+        }
+        out
+    }
+
+    /// First time in minutes within `duration` at which `utilisation`, given as a function of
+    /// the steel temperature, reaches 1.0
+    #[must_use]
+    pub fn critical_time(&self, duration: f64, utilisation: impl Fn(f64) -> f64) -> Option<f64> {
+        self.history(duration)
+            .into_iter()
+            .find(|(_, theta)| utilisation(*theta) >= 1.0)
+            .map(|(t, _)| t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crs::standard::PresetCrs;
+    use crate::erc::NSEN_1993_1_2::k_y_theta;
+    use crate::load::fire::ParametricFire;
+
+    fn heb() -> PresetCrs {
+        PresetCrs::from_name("HEB 300").expect("Couldnt create crs")
+    }
+
+    #[test]
+    fn unprotected_steel_follows_the_gas() {
+        let heating = Heating::new(FireCurve::Iso834, &heb());
+        let history = heating.history(60.0);
+        assert_eq!(history.len(), 721);
+        assert!(history.windows(2).all(|w| w[1].1 >= w[0].1));
+        let (_, theta_30) = history[360];
+        assert!(theta_30 > 600.0 && theta_30 < FireCurve::Iso834.gas_temperature(30.0));
+        let (_, theta_60) = history[720];
+        assert!(FireCurve::Iso834.gas_temperature(60.0) - theta_60 < 20.0);
+    }
+
+    #[test]
+    fn protection_slows_heating() {
+        let bare = Heating::new(FireCurve::Iso834, &heb());
+        let board = bare
+            .clone()
+            .with_protection(Protection::new(0.2, 800.0, 1700.0, 0.02));
+        let (_, bare_60) = *bare.history(60.0).last().expect("Empty history");
+        let (_, board_60) = *board.history(60.0).last().expect("Empty history");
+        assert!(board_60 < bare_60 - 300.0);
+    }
+
+    #[test]
+    fn parametric_fire_cools_down() {
+        let fire = ParametricFire::new(0.04, 1160.0, 300.0, 20.0 / 60.0);
+        let heating = Heating::new(FireCurve::Parametric(fire), &heb());
+        let history = heating.history(240.0);
+        let (_, peak) = history
+            .iter()
+            .fold((0.0, 0.0), |a, b| if b.1 > a.1 { *b } else { a });
+        assert!(history.last().expect("Empty history").1 < peak);
+    }
+
+    #[test]
+    fn critical_time_at_critical_temperature() {
+        // Utilisation 0.5 at room temperature is reached when k_y falls to 0.5
+        let heating = Heating::new(FireCurve::Iso834, &heb());
+        let t = heating
+            .critical_time(60.0, |theta| 0.5 / k_y_theta(theta))
+            .expect("Member should fail within 60 minutes");
+        let (_, theta) = heating.history(t).last().copied().expect("Empty history");
+        assert!(theta > 585.0 && theta < 595.0, "{theta}");
+        assert!(heating.critical_time(60.0, |_| 0.5).is_none());
+    }
+}
@@ -1 +1,7 @@
+pub mod aluminiumbeam;
+pub mod buckling;
 pub mod columnbeam;
+pub mod concretecolumn;
+pub mod fibresection;
+pub mod heating;
+pub mod timberbeam;
@@ -0,0 +1,176 @@
+use crate::crs::{rect::Rect, CrossSection};
+use crate::erc::NSEN_1995::{f_6_11, f_6_12, f_6_13, f_6_17, f_6_19, f_6_21, f_6_23, f_6_25};
+use crate::load::loadcase::LoadCase;
+use crate::mat::timber::Timber;
+use crate::{Axis, LimitStateType};
+use serde::{Deserialize, Serialize};
+
+/// Factor for redistribution of bending stresses in rectangular sections, 6.1.6(2)
+const K_M: f64 = 0.7;
+/// Factor for the effect of cracks on the shear width, NS-EN 1995-1-1 6.1.7(2)
+const K_CR: f64 = 0.67;
+
+/// Rectangular timber member checked according to NS-EN 1995-1-1 section 6
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TimberBeam {
+    pub crs: Rect,
+    pub mat: Timber,
+}
+
+impl Default for TimberBeam {
+    fn default() -> Self {
+        Self::new(Rect::default(), Timber::default())
+    }
+}
+
+impl TimberBeam {
+    #[must_use]
+    pub const fn new(crs: Rect, mat: Timber) -> Self {
+        Self { crs, mat }
+    }
+
+    /// Design bending strength including the size factor for the depth in the bending plane
+    fn f_m(&self, axis: Axis, limit_state_type: &LimitStateType) -> f64 {
+        let h = match axis {
+            Axis::Y | Axis::X => self.crs.height(),
+            Axis::Z => self.crs.width(),
+        };
+        self.mat.k_h(h) * self.mat.f_m(limit_state_type)
+    }
+
+    /// Utilisation of the worse of 6.11 and 6.12 for the bending moments in N mm
+    #[allow(clippy::similar_names)]
+    #[must_use]
+    pub fn bending(&self, my: f64, mz: f64, limit_state_type: &LimitStateType) -> f64 {
+        let sigma_my = my.abs() / self.crs.w_el(Axis::Y);
+        let sigma_mz = mz.abs() / self.crs.w_el(Axis::Z);
+        let f_my = self.f_m(Axis::Y, limit_state_type);
+        let f_mz = self.f_m(Axis::Z, limit_state_type);
+        f_6_11(sigma_my, sigma_mz, f_my, f_mz, K_M).max(f_6_12(sigma_my, sigma_mz, f_my, f_mz, K_M))
+    }
+
+    /// Utilisation in shear, 6.1.7, for shear forces in N along the y- and z-axis
+    #[must_use]
+    pub fn shear(&self, vy: f64, vz: f64, limit_state_type: &LimitStateType) -> f64 {
+        let b = self.crs.width();
+        let h = self.crs.height();
+        let tau_y = f_6_13(vy.abs(), K_CR * h, b);
+        let tau_z = f_6_13(vz.abs(), K_CR * b, h);
+        tau_y.max(tau_z) / self.mat.f_v(limit_state_type)
+    }
+
+    /// Relative slenderness for the buckling length `lk` in mm, 6.3.2(1)
+    #[must_use]
+    pub fn lambda_rel(&self, lk: f64, axis: Axis) -> f64 {
+        let radius = (self.crs.I(axis) / self.crs.area()).sqrt();
+        f_6_21(
+            lk / radius,
+            self.mat.f_c_0(&LimitStateType::K),
+            self.mat.e_0_05(),
+        )
+    }
+    /// Instability factor for the buckling length `lk` in mm, 6.3.2(3)
+    #[must_use]
+    pub fn k_c(&self, lk: f64, axis: Axis) -> f64 {
+        f_6_25(self.lambda_rel(lk, axis), self.mat.beta_c())
+    }
+
+    /// Utilisation of a centric column with compression force `n` in N and buckling lengths
+    /// `lk_y` and `lk_z` in mm, 6.3.2(3)
+    #[must_use]
+    pub fn compression(
+        &self,
+        n: f64,
+        lk_y: f64,
+        lk_z: f64,
+        limit_state_type: &LimitStateType,
+    ) -> f64 {
+        let k_c = self.k_c(lk_y, Axis::Y).min(self.k_c(lk_z, Axis::Z));
+        n.abs() / (self.crs.area() * k_c * self.mat.f_c_0(limit_state_type))
+    }
+
+    /// Utilisation of combined axial force and bending, 6.2.3, 6.2.4 and 6.3.2(3), where the
+    /// axial force of the load case is positive in tension
+    #[allow(clippy::similar_names)]
+    #[must_use]
+    pub fn combined(
+        &self,
+        load: &LoadCase,
+        lk_y: f64,
+        lk_z: f64,
+        limit_state_type: &LimitStateType,
+    ) -> f64 {
+        let sigma_my = load.My.abs() / self.crs.w_el(Axis::Y);
+        let sigma_mz = load.Mz.abs() / self.crs.w_el(Axis::Z);
+        let f_my = self.f_m(Axis::Y, limit_state_type);
+        let f_mz = self.f_m(Axis::Z, limit_state_type);
+        let bending_y = f_6_11(sigma_my, sigma_mz, f_my, f_mz, K_M);
+        let bending_z = f_6_12(sigma_my, sigma_mz, f_my, f_mz, K_M);
+        let sigma = load.N.abs() / self.crs.area();
+
+        if load.N >= 0.0 {
+            let h = self.crs.width().max(self.crs.height());
+            let f_t = self.mat.k_h(h) * self.mat.f_t_0(limit_state_type);
+            return f_6_17(sigma, f_t, bending_y).max(f_6_17(sigma, f_t, bending_z));
+        }
+        let f_c = self.mat.f_c_0(limit_state_type);
+        let lambda_y = self.lambda_rel(lk_y, Axis::Y);
+        let lambda_z = self.lambda_rel(lk_z, Axis::Z);
+        if lambda_y <= 0.3 && lambda_z <= 0.3 {
+            f_6_19(sigma, f_c, bending_y).max(f_6_19(sigma, f_c, bending_z))
+        } else {
+            let k_c_y = f_6_25(lambda_y, self.mat.beta_c());
+            let k_c_z = f_6_25(lambda_z, self.mat.beta_c());
+            f_6_23(sigma, k_c_y, f_c, bending_y).max(f_6_23(sigma, k_c_z, f_c, bending_z))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mat::timber::Variant;
+    use crate::zequality::Zeq;
+
+    fn beam() -> TimberBeam {
+        TimberBeam::new(Rect::new(48.0, 198.0), Timber::from(&Variant::C24))
+    }
+
+    #[test]
+    fn bending_utilisation() {
+        // W_y = 48 * 198^2 / 6 = 313_632 mm^3, f_m,d = 0.8 * 24 / 1.25 = 15.36
+        let mmb = beam();
+        assert_zeq!(mmb.bending(4.0e6, 0.0, &LimitStateType::D), 0.830_326);
+    }
+
+    #[test]
+    fn shear_utilisation() {
+        // tau = 1.5 * 10 kN / (0.67 * 48 * 198), f_v,d = 0.8 * 4.0 / 1.25
+        let mmb = beam();
+        assert_zeq!(mmb.shear(0.0, 10_000.0, &LimitStateType::D), 0.920_174);
+    }
+
+    #[test]
+    fn slender_column_is_reduced() {
+        let mmb = beam();
+        assert_zeq!(mmb.k_c(100.0, Axis::Z), 1.0);
+        assert!(mmb.k_c(3000.0, Axis::Z) < mmb.k_c(3000.0, Axis::Y));
+        let stocky = mmb.compression(10_000.0, 100.0, 100.0, &LimitStateType::D);
+        assert!(mmb.compression(10_000.0, 3000.0, 3000.0, &LimitStateType::D) > stocky);
+    }
+
+    #[test]
+    fn combined_compression_and_bending() {
+        let mmb = beam();
+        let load = LoadCase::new().axial_kN(-20.0).moment_kNm(0.0, 2.0, 0.0);
+        let combined = mmb.combined(&load, 3000.0, 1000.0, &LimitStateType::D);
+        assert!(combined > mmb.bending(load.My, 0.0, &LimitStateType::D));
+        assert!(combined > mmb.compression(load.N, 3000.0, 1000.0, &LimitStateType::D));
+        let tension = LoadCase::new().axial_kN(20.0).moment_kNm(0.0, 2.0, 0.0);
+        // 6.17 without buckling, f_t,d = 0.8 * 14.5 / 1.25
+        assert_zeq!(
+            mmb.combined(&tension, 3000.0, 1000.0, &LimitStateType::D),
+            0.641_928
+        );
+    }
+}